@@ -1,17 +1,57 @@
-﻿use crate::{events, methods};
+﻿use crate::events;
 use crate::interfaces::contract::RentACarContractTrait;
-use crate::methods::token::token::token_transfer;
-use crate::storage::admin::{has_admin, read_admin, write_admin, read_admin_commission, write_admin_commission, read_admin_available_to_withdraw, write_admin_available_to_withdraw};
-use crate::storage::car::{has_car, read_car, remove_car, write_car};
+use crate::methods::token::token::{check_token_accepted, token_transfer};
+use crate::storage::admin::{has_admin, read_admin, write_admin, read_commission_config, write_commission_config, read_admin_available_to_withdraw, write_admin_available_to_withdraw, write_late_grace_period, read_deposit_claim_window, write_deposit_claim_window, read_settlement_keeper, write_settlement_keeper, remove_settlement_keeper};
+use crate::storage::booking::{next_booking_id, read_bookings, write_bookings};
+use crate::storage::cancellation_policy::{write_cancellation_policy, write_car_cancellation_policy};
+use crate::storage::car::{add_car_to_index, add_owner_car, has_car, read_owner_cars, next_car_id, read_car, read_car_available_since, read_car_balance, read_car_balance_tokens, remove_car_from_index, write_car, write_car_available_since, write_car_balance};
+use crate::storage::commission::{read_commission_tiers, read_owner_commission, remove_owner_commission, write_commission_tiers, write_owner_commission};
 use crate::storage::contract_balance::{read_contract_balance, write_contract_balance};
-use crate::storage::rental::{write_rental, remove_rental, has_rental};
+use crate::storage::deposit_claim::{has_deposit_claim, read_deposit_claim, write_deposit_claim};
+use crate::storage::driver_approval::{has_driver_approval, remove_driver_approval, write_driver_approval};
+use crate::storage::early_return_policy::write_early_return_policy;
+use crate::storage::escrow::{read_escrow_balance, write_escrow_balance};
+use crate::storage::fee_bearer::{read_fee_bearer, write_fee_bearer, write_owner_fee_bearer};
+use crate::storage::maintenance::{read_maintenance_log, write_maintenance_log};
+use crate::storage::owner_volume::{read_owner_volume, write_owner_volume};
+use crate::storage::payout_settings::{read_payout_settings, write_payout_settings};
+use crate::storage::rental::{write_rental, read_rental, remove_rental, has_rental, has_active_rentals};
+use crate::storage::structs::booking::Booking;
+use crate::storage::structs::cancellation_policy::CancellationPolicy;
 use crate::storage::structs::car::Car;
-use crate::storage::structs::rental::Rental;
-use crate::storage::token::write_token;
+use crate::storage::structs::commission_config::{validate_commission_tiers, CommissionConfig, CommissionTier};
+use crate::storage::structs::deposit_claim::DepositClaim;
+use crate::storage::structs::car_details::CarDetails;
+use crate::storage::structs::car_filter::CarFilter;
+use crate::storage::structs::car_page::CarPage;
+use crate::storage::structs::early_return_policy::EarlyReturnPolicy;
+use crate::storage::structs::earnings_statement::EarningsStatement;
+use crate::storage::structs::maintenance_record::MaintenanceRecord;
+use crate::storage::structs::owner_balance::OwnerBalance;
+use crate::storage::structs::owner_volume::OwnerVolume;
+use crate::storage::structs::payout_settings::PayoutSettings;
+use crate::storage::structs::reconciliation::Reconciliation;
+use crate::storage::structs::settle_cursor::SettleCursor;
+use crate::storage::structs::rental::{Rental, SECONDS_PER_DAY};
+use crate::storage::structs::rental_quote::RentalQuote;
+use crate::storage::structs::rental_time_status::RentalTimeStatus;
+use crate::storage::token::{read_allowed_tokens, read_token, write_allowed_tokens, write_car_tokens, write_migrated_token, write_token};
 use crate::storage::types::car_status::CarStatus;
 use crate::storage::types::errors::Error;
-use soroban_sdk::{contract, contractimpl, Address, Env};
+use crate::storage::types::fee_bearer::FeeBearer;
+use crate::storage::types::late_fee_unit::LateFeeUnit;
+use crate::storage::types::payer::Payer;
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, String, Vec};
 use crate::methods::public;
+use crate::methods::public::get_quote::quote_rental;
+use crate::methods::rental::booking::{has_booking_conflict, insert_booking, release_booking_funds, settle_expired_bookings};
+use crate::methods::rental::cancellation::compute_cancellation_refund;
+use crate::methods::rental::payer::{charge_payer, check_payer};
+use crate::methods::rental::deposit::{release_deposit_claim, release_expired_deposit_claim, withhold_from_claim};
+use crate::methods::rental::early_return::compute_early_return_refund;
+use crate::methods::rental::late_fee::compute_late_penalty;
+use crate::methods::rental::settlement::{next_settle_cursor, settle_cars, SettleBudget};
+use crate::methods::rental::statement::{record_earnings, record_statement, record_withdrawal};
 use crate::methods::public::get_owner_available_to_withdraw as get_owner_available_to_withdraw_public;
 
 
@@ -25,180 +65,1201 @@ impl RentACarContractTrait for RentACarContract {
             return Err(Error::AdminTokenConflict);
         }
 
-        if has_admin(&env) {
+        if has_admin(env) {
             return Err(Error::ContractInitialized);
         }
 
         write_admin(env, &admin);
         write_token(env, &token);
+        write_allowed_tokens(env, &vec![env, token.clone()]);
 
         events::contract::contract_initialized(env, admin, token);
 
         Ok(())
     }
 
-    fn add_car(env: &Env, owner: Address, price_per_day: i128) -> Result<(), Error> {
+    fn add_car(
+        env: &Env,
+        owner: Address,
+        price_per_day: i128,
+        details: CarDetails,
+    ) -> Result<u32, Error> {
         let admin = read_admin(env)?;
         admin.require_auth();
 
-        if price_per_day <= 0 {
-            return Err(Error::AmountMustBePositive);
+        if price_per_day <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        details.validate()?;
+
+        let car_id = next_car_id(env)?;
+
+        let car = Car {
+            owner: owner.clone(),
+            details,
+            price_per_day,
+            deposit: 0,
+            late_fee: 0,
+            late_fee_unit: LateFeeUnit::Day,
+            car_status: CarStatus::Available,
+        };
+
+        write_car(env, car_id, &car);
+        add_owner_car(env, &owner, car_id);
+        add_car_to_index(env, car_id);
+        events::add_car::car_added(env, owner, car_id, price_per_day);
+        Ok(car_id)
+    }
+
+    fn update_car_details(
+        env: &Env,
+        caller: Address,
+        car_id: u32,
+        details: CarDetails,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+        let admin = read_admin(env)?;
+
+        // Only the car owner or the admin can change the catalog entry
+        if caller != car.owner && caller != admin {
+            return Err(Error::Unauthorized);
+        }
+
+        details.validate()?;
+
+        car.details = details.clone();
+
+        write_car(env, car_id, &car);
+        events::update_car_details::car_details_updated(env, car.owner, car_id, details);
+        Ok(())
+    }
+
+    fn start_maintenance(env: &Env, caller: Address, car_id: u32, reason: String) -> Result<(), Error> {
+        caller.require_auth();
+
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+        let admin = read_admin(env)?;
+
+        if caller != car.owner && caller != admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if car.car_status == CarStatus::Rented {
+            return Err(Error::CarAlreadyRented);
+        }
+
+        if car.car_status == CarStatus::Maintenance {
+            return Err(Error::CarInMaintenance);
+        }
+
+        if car.car_status == CarStatus::Retired {
+            return Err(Error::CarRetired);
+        }
+
+        // Bookings that ended while the car was available are settled before it goes out
+        let bookings = settle_expired_bookings(env, car_id)?;
+        write_bookings(env, car_id, &bookings);
+
+        car.car_status = CarStatus::Maintenance;
+
+        let mut log = read_maintenance_log(env, car_id);
+        log.push_back(MaintenanceRecord {
+            reason: reason.clone(),
+            start_ts: env.ledger().timestamp(),
+            end_ts: 0,
+        });
+
+        write_maintenance_log(env, car_id, &log);
+        write_car(env, car_id, &car);
+        events::maintenance::maintenance_started(env, car.owner, car_id, reason);
+        Ok(())
+    }
+
+    fn end_maintenance(env: &Env, caller: Address, car_id: u32) -> Result<(), Error> {
+        caller.require_auth();
+
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+        let admin = read_admin(env)?;
+
+        if caller != car.owner && caller != admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if car.car_status == CarStatus::Rented {
+            return Err(Error::CarAlreadyRented);
+        }
+
+        if car.car_status != CarStatus::Maintenance {
+            return Err(Error::CarNotInMaintenance);
+        }
+
+        car.car_status = CarStatus::Available;
+
+        // The open entry is always the last one
+        let mut log = read_maintenance_log(env, car_id);
+        let last = log.len().checked_sub(1).ok_or(Error::CarNotInMaintenance)?;
+        let mut record = log.get_unchecked(last);
+
+        record.end_ts = env.ledger().timestamp();
+        let downtime = record.end_ts.saturating_sub(record.start_ts);
+        log.set(last, record);
+
+        write_maintenance_log(env, car_id, &log);
+        write_car(env, car_id, &car);
+        write_car_available_since(env, car_id, env.ledger().timestamp());
+        events::maintenance::maintenance_ended(env, car.owner, car_id, downtime);
+        Ok(())
+    }
+
+    fn set_car_deposit(env: &Env, car_id: u32, deposit: i128) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+        car.owner.require_auth();
+
+        if deposit < 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        car.deposit = deposit;
+
+        write_car(env, car_id, &car);
+        events::deposit::deposit_set(env, car.owner, car_id, deposit);
+        Ok(())
+    }
+
+    fn withhold_deposit(env: &Env, car_id: u32, amount: i128, reason: u32) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+        car.owner.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        // After the return the owner can still withhold from the deposit until the claim window closes
+        if !has_rental(env, car_id) && has_deposit_claim(env, car_id) {
+            return withhold_from_claim(env, car_id, car.owner, amount, reason);
+        }
+
+        if !has_rental(env, car_id) {
+            return Err(Error::RentalNotFound);
+        }
+
+        let mut rental = read_rental(env, car_id)?;
+
+        let withheld = rental
+            .deposit_withheld
+            .checked_add(amount)
+            .ok_or(Error::OverflowError)?;
+
+        if withheld > rental.deposit {
+            return Err(Error::WithholdExceedsDeposit);
+        }
+
+        // The withheld part is only moved to the owner when the car is returned
+        rental.deposit_withheld = withheld;
+        rental.withhold_reason = reason;
+
+        write_rental(env, car_id, &rental);
+        events::deposit::deposit_withheld(env, car.owner, car_id, amount, reason);
+        Ok(())
+    }
+
+    fn release_deposit(env: &Env, car_id: u32) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+        let claim = read_deposit_claim(env, car_id)?;
+
+        // Anyone can release once the window closes, before that only the owner can settle early
+        if env.ledger().timestamp() < claim.release_ts {
+            car.owner.require_auth();
+        }
+
+        release_deposit_claim(env, car_id)
+    }
+
+    fn get_deposit_claim(env: &Env, car_id: u32) -> Result<DepositClaim, Error> {
+        public::get_rental::get_deposit_claim(env, car_id)
+    }
+
+    fn set_deposit_claim_window(env: &Env, claim_window: u64) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        write_deposit_claim_window(env, claim_window);
+        events::deposit::deposit_claim_window_set(env, claim_window);
+        Ok(())
+    }
+
+    fn set_car_late_fee(env: &Env, car_id: u32, late_fee: i128, unit: LateFeeUnit) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+        car.owner.require_auth();
+
+        if late_fee < 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        car.late_fee = late_fee;
+        car.late_fee_unit = unit;
+
+        write_car(env, car_id, &car);
+        events::late_fee::late_fee_set(env, car.owner, car_id, late_fee, unit);
+        Ok(())
+    }
+
+    fn set_car_early_return_policy(env: &Env, car_id: u32, policy: EarlyReturnPolicy) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+        car.owner.require_auth();
+
+        if policy.fee < 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        write_early_return_policy(env, car_id, &policy);
+        events::early_return::early_return_policy_set(env, car.owner, car_id, policy);
+        Ok(())
+    }
+
+    fn set_late_grace_period(env: &Env, grace_period: u64) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        write_late_grace_period(env, grace_period);
+        events::late_fee::late_grace_period_set(env, grace_period);
+        Ok(())
+    }
+
+    fn set_cancellation_policy(env: &Env, policy: CancellationPolicy) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        policy.validate()?;
+
+        write_cancellation_policy(env, &policy);
+        events::cancellation::cancellation_policy_set(env, policy);
+        Ok(())
+    }
+
+    fn set_car_cancellation_policy(env: &Env, car_id: u32, policy: CancellationPolicy) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+        car.owner.require_auth();
+
+        policy.validate()?;
+
+        write_car_cancellation_policy(env, car_id, &policy);
+        events::cancellation::car_cancellation_policy_set(env, car.owner, car_id, policy);
+        Ok(())
+    }
+
+    fn get_cancellation_policy(env: &Env, car_id: u32) -> Result<CancellationPolicy, Error> {
+        public::get_cancellation_policy::get_cancellation_policy(env, car_id)
+    }
+
+    fn get_maintenance_log(env: &Env, car_id: u32) -> Result<Vec<MaintenanceRecord>, Error> {
+        public::get_maintenance_log::get_maintenance_log(env, car_id)
+    }
+
+    fn get_maintenance_downtime(env: &Env, car_id: u32) -> Result<u64, Error> {
+        public::get_maintenance_log::get_maintenance_downtime(env, car_id)
+    }
+
+    fn get_car(env: &Env, car_id: u32) -> Result<Car, Error> {
+        public::get_car::get_car(env, car_id)
+    }
+
+    fn get_car_balance(env: &Env, car_id: u32, token: Address) -> Result<OwnerBalance, Error> {
+        public::get_car::get_car_balance(env, car_id, token)
+    }
+
+    fn get_car_tokens(env: &Env, car_id: u32) -> Result<Vec<Address>, Error> {
+        public::get_car::get_car_tokens(env, car_id)
+    }
+
+    fn set_car_tokens(env: &Env, car_id: u32, tokens: Vec<Address>) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+        car.owner.require_auth();
+
+        let allowed_tokens = read_allowed_tokens(env);
+
+        if tokens.is_empty() || tokens.iter().any(|token| !allowed_tokens.contains(&token)) {
+            return Err(Error::TokenNotAllowed);
+        }
+
+        write_car_tokens(env, car_id, &tokens);
+        events::token::car_tokens_set(env, car.owner, car_id, tokens);
+        Ok(())
+    }
+
+    fn allow_token(env: &Env, token: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        let mut tokens = read_allowed_tokens(env);
+
+        if !tokens.contains(&token) {
+            tokens.push_back(token.clone());
+            write_allowed_tokens(env, &tokens);
+        }
+
+        events::token::token_allowed(env, token);
+        Ok(())
+    }
+
+    fn disallow_token(env: &Env, token: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        // Balances already held in the token can still be withdrawn, only new payments are refused
+        let mut tokens = read_allowed_tokens(env);
+
+        if let Some(index) = tokens.first_index_of(&token) {
+            tokens.remove(index);
+            write_allowed_tokens(env, &tokens);
+        }
+
+        events::token::token_disallowed(env, token);
+        Ok(())
+    }
+
+    fn get_allowed_tokens(env: &Env) -> Vec<Address> {
+        read_allowed_tokens(env)
+    }
+
+    fn migrate_token(env: &Env, new_token: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        if admin == new_token {
+            return Err(Error::AdminTokenConflict);
+        }
+
+        if has_active_rentals(env) {
+            return Err(Error::ActiveRentalsExist);
+        }
+
+        // Owner and admin funds live in the contract balance, deposits and prepaid bookings in escrow
+        let old_token = read_token(env)?;
+
+        if read_contract_balance(env, &old_token) != 0 || read_escrow_balance(env, &old_token) != 0 {
+            return Err(Error::TokenBalanceNotEmpty);
+        }
+
+        let mut tokens = read_allowed_tokens(env);
+
+        if let Some(index) = tokens.first_index_of(&old_token) {
+            tokens.remove(index);
+        }
+
+        if !tokens.contains(&new_token) {
+            tokens.push_back(new_token.clone());
+        }
+
+        // Per-car token lists are read through the migration, so cars that accepted the old token accept the new one
+        write_allowed_tokens(env, &tokens);
+        write_token(env, &new_token);
+        write_migrated_token(env, &old_token, &new_token);
+
+        events::token::token_migrated(env, old_token, new_token);
+        Ok(())
+    }
+
+    fn list_cars(env: &Env, cursor: u32, limit: u32, filter: CarFilter) -> Result<CarPage, Error> {
+        public::list_cars::list_cars(env, cursor, limit, &filter)
+    }
+
+    fn get_car_status(env: &Env, car_id: u32) -> Result<CarStatus, Error> {
+        public::get_car_status::get_car_status(env, car_id)
+    }
+
+    fn get_owner_cars(env: &Env, owner: Address) -> Vec<u32> {
+        public::get_owner_cars::get_owner_cars(env, &owner)
+    }
+
+    fn get_admin_available_to_withdraw(env: &Env, token: Address) -> i128 {
+        public::get_admin_available_to_withdraw::get_admin_available_to_withdraw(env, &token)
+    }
+
+    fn reconcile(env: &Env, token: Address) -> Reconciliation {
+        public::reconcile::reconcile(env, &token)
+    }
+
+    fn sweep_surplus(env: &Env, token: Address, to: Address) -> Result<i128, Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        // Only tokens held beyond what is owed to owners, the admin and escrow can leave
+        let surplus = public::reconcile::reconcile(env, &token).surplus;
+
+        if surplus <= 0 {
+            return Err(Error::NoSurplusToSweep);
+        }
+
+        token_transfer(env, &token, &env.current_contract_address(), &to, &surplus);
+        events::token::surplus_swept(env, token, to, surplus);
+        Ok(surplus)
+    }
+
+    fn get_owner_available_to_withdraw(env: &Env, owner: Address, token: Address) -> OwnerBalance {
+        get_owner_available_to_withdraw_public::get_owner_available_to_withdraw(env, &owner, &token)
+    }
+
+    fn get_quote(env: &Env, car_id: u32, total_days_to_rent: u32) -> Result<RentalQuote, Error> {
+        public::get_quote::get_quote(env, car_id, total_days_to_rent)
+    }
+
+    fn get_rental(env: &Env, car_id: u32) -> Result<Rental, Error> {
+        public::get_rental::get_rental(env, car_id)
+    }
+
+    fn get_rental_time_status(env: &Env, car_id: u32) -> Result<RentalTimeStatus, Error> {
+        public::get_rental::get_rental_time_status(env, car_id)
+    }
+
+    fn rental(
+        env: &Env,
+        renter: Address,
+        car_id: u32,
+        total_days_to_rent: u32,
+        amount: i128,
+        token: Address,
+    ) -> Result<(), Error> {
+        Self::rental_paid_by(env, renter.clone(), car_id, total_days_to_rent, amount, token, Payer::Auth(renter))
+    }
+
+    fn rental_paid_by(
+        env: &Env,
+        renter: Address,
+        car_id: u32,
+        total_days_to_rent: u32,
+        amount: i128,
+        token: Address,
+        payer: Payer,
+    ) -> Result<(), Error> {
+        renter.require_auth();
+
+        check_payer(env, &payer, &renter)?;
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        if total_days_to_rent == 0 {
+            return Err(Error::RentalDurationCannotBeZero);
+        }
+
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+
+        if renter == car.owner {
+            return Err(Error::SelfRentalNotAllowed);
+        }
+
+        if car.car_status == CarStatus::Retired {
+            return Err(Error::CarRetired);
+        }
+
+        if car.car_status == CarStatus::Maintenance {
+            return Err(Error::CarInMaintenance);
+        }
+
+        if car.car_status != CarStatus::Available {
+            return Err(Error::CarAlreadyRented);
+        }
+
+        check_token_accepted(env, car_id, &token)?;
+
+        // The deposit of the previous return has to be settled before the car goes out again
+        release_expired_deposit_claim(env, car_id)?;
+
+        let start_ts = env.ledger().timestamp();
+        let due_ts = (total_days_to_rent as u64)
+            .checked_mul(SECONDS_PER_DAY)
+            .and_then(|duration| start_ts.checked_add(duration))
+            .ok_or(Error::OverflowError)?;
+
+        // A walk-in rental must be over before the next reservation starts
+        let bookings = settle_expired_bookings(env, car_id)?;
+        if has_booking_conflict(&bookings, start_ts, due_ts) {
+            return Err(Error::BookingConflict);
+        }
+        write_bookings(env, car_id, &bookings);
+
+        // The price is always derived from the car, the amount is the most the payer agreed to transfer
+        let quote = quote_rental(env, &car, total_days_to_rent, &token, read_fee_bearer(env, &car.owner))?;
+
+        if amount < quote.total {
+            return Err(Error::InsufficientPayment);
+        }
+
+        car.car_status = CarStatus::Rented;
+        
+        let admin_commission = quote.admin_commission;
+        let total_amount = quote.total;
+
+        // Owner receives the rental amount, minus the commission when the owner bears it, pending until the car is returned
+        let mut balance = read_car_balance(env, car_id, &token);
+        balance.pending = balance
+            .pending
+            .checked_add(quote.owner_amount)
+            .ok_or(Error::OverflowError)?;
+
+        // Accumulate commission for admin
+        let mut admin_available = read_admin_available_to_withdraw(env, &token);
+        admin_available = admin_available
+            .checked_add(admin_commission)
+            .ok_or(Error::OverflowError)?;
+        write_admin_available_to_withdraw(env, &token, admin_available);
+
+        // Commission, fee bearer and token are fixed for the whole rental
+        let rental = Rental {
+            renter: renter.clone(),
+            payer: payer.clone(),
+            total_days_to_rent,
+            amount: quote.base_price,
+            admin_commission,
+            fee_bearer: quote.fee_bearer,
+            token: token.clone(),
+            start_ts,
+            due_ts,
+            deposit: quote.deposit,
+            deposit_withheld: 0,
+            withhold_reason: 0,
+            late_fee: car.late_fee,
+            late_fee_unit: car.late_fee_unit,
+        };
+
+        let mut contract_balance = read_contract_balance(env, &token);
+        // Contract balance includes both the owner share and commission
+        contract_balance = contract_balance
+            .checked_add(quote.owner_amount)
+            .and_then(|balance| balance.checked_add(admin_commission))
+            .ok_or(Error::OverflowError)?;
+
+        // The deposit is held in escrow, apart from the earnings
+        let escrow_balance = read_escrow_balance(env, &token)
+            .checked_add(quote.deposit)
+            .ok_or(Error::OverflowError)?;
+
+        write_contract_balance(env, &token, &contract_balance);
+        write_escrow_balance(env, &token, &escrow_balance);
+        write_car_balance(env, car_id, &token, &balance);
+        write_car(env, car_id, &car);
+        write_rental(env, car_id, &rental);
+
+        let fees_paid = quote
+            .base_price
+            .checked_sub(quote.owner_amount)
+            .ok_or(Error::OverflowError)?;
+        record_earnings(env, car_id, &car.owner, &token, 1, quote.owner_amount, fees_paid)?;
+
+        // Payer covers the rental amount, the commission if the renter bears it, and the deposit
+        charge_payer(env, &token, &payer, &total_amount);
+        events::rental::rented(env, renter.clone(), car.owner, car_id, token, total_days_to_rent, &quote);
+
+        if payer.address() != renter {
+            events::rental::rental_paid_by(env, payer.address(), renter.clone(), car_id, total_amount);
+        }
+
+        if quote.deposit > 0 {
+            events::deposit::deposit_held(env, renter, car_id, quote.deposit);
+        }
+        Ok(())
+    }
+
+    fn extend_rental(env: &Env, renter: Address, car_id: u32, extra_days: u32) -> Result<(), Error> {
+        renter.require_auth();
+
+        if extra_days == 0 {
+            return Err(Error::RentalDurationCannotBeZero);
+        }
+
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+
+        if !has_rental(env, car_id) {
+            return Err(Error::RentalNotFound);
+        }
+
+        let mut rental = read_rental(env, car_id)?;
+
+        if rental.renter != renter || car.car_status != CarStatus::Rented {
+            return Err(Error::RentalNotFound);
+        }
+
+        // Extensions are charged to whoever paid for the rental, under the same rules
+        check_payer(env, &rental.payer, &renter)?;
+
+        // An overdue rental has to be returned, extending it would wipe the late penalty
+        if env.ledger().timestamp() >= rental.due_ts {
+            return Err(Error::RentalOverdue);
+        }
+
+        let due_ts = (extra_days as u64)
+            .checked_mul(SECONDS_PER_DAY)
+            .and_then(|duration| rental.due_ts.checked_add(duration))
+            .ok_or(Error::OverflowError)?;
+
+        let bookings = settle_expired_bookings(env, car_id)?;
+        if has_booking_conflict(&bookings, rental.due_ts, due_ts) {
+            return Err(Error::BookingConflict);
+        }
+        write_bookings(env, car_id, &bookings);
+
+        // The deposit is already held, only the extra days and their commission are charged
+        let quote = quote_rental(env, &car, extra_days, &rental.token, rental.fee_bearer)?;
+        let extension_amount = quote
+            .total
+            .checked_sub(quote.deposit)
+            .ok_or(Error::OverflowError)?;
+
+        rental.total_days_to_rent = rental
+            .total_days_to_rent
+            .checked_add(extra_days)
+            .ok_or(Error::OverflowError)?;
+        rental.amount = rental
+            .amount
+            .checked_add(quote.base_price)
+            .ok_or(Error::OverflowError)?;
+        rental.admin_commission = rental
+            .admin_commission
+            .checked_add(quote.admin_commission)
+            .ok_or(Error::OverflowError)?;
+        rental.due_ts = due_ts;
+
+        let mut balance = read_car_balance(env, car_id, &rental.token);
+        balance.pending = balance
+            .pending
+            .checked_add(quote.owner_amount)
+            .ok_or(Error::OverflowError)?;
+
+        let admin_available = read_admin_available_to_withdraw(env, &rental.token)
+            .checked_add(quote.admin_commission)
+            .ok_or(Error::OverflowError)?;
+        let contract_balance = read_contract_balance(env, &rental.token)
+            .checked_add(extension_amount)
+            .ok_or(Error::OverflowError)?;
+
+        write_admin_available_to_withdraw(env, &rental.token, admin_available);
+        write_contract_balance(env, &rental.token, &contract_balance);
+        write_car_balance(env, car_id, &rental.token, &balance);
+        write_rental(env, car_id, &rental);
+
+        let fees_paid = quote
+            .base_price
+            .checked_sub(quote.owner_amount)
+            .ok_or(Error::OverflowError)?;
+        record_earnings(env, car_id, &car.owner, &rental.token, 0, quote.owner_amount, fees_paid)?;
+
+        charge_payer(env, &rental.token, &rental.payer, &extension_amount);
+        events::rental::rental_extended(env, renter, car.owner, car_id, extra_days, due_ts, quote.base_price);
+        Ok(())
+    }
+
+    fn reserve(
+        env: &Env,
+        renter: Address,
+        car_id: u32,
+        start_ts: u64,
+        end_ts: u64,
+        token: Address,
+    ) -> Result<u32, Error> {
+        renter.require_auth();
+
+        if start_ts >= end_ts || start_ts < env.ledger().timestamp() {
+            return Err(Error::InvalidBookingRange);
+        }
+
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+
+        if renter == car.owner {
+            return Err(Error::SelfRentalNotAllowed);
         }
 
-        if has_car(env, &owner) {
-            return Err(Error::CarAlreadyExist);
+        if car.car_status == CarStatus::Retired {
+            return Err(Error::CarRetired);
         }
 
-        let car = Car {
-            price_per_day,
-            car_status: CarStatus::Available,
-            available_to_withdraw: 0,
-        };
+        check_token_accepted(env, car_id, &token)?;
 
-        write_car(env, &owner, &car);
-        events::add_car::car_added(env, owner, price_per_day);
-        Ok(())
-    }
+        let mut bookings = settle_expired_bookings(env, car_id)?;
+        if has_booking_conflict(&bookings, start_ts, end_ts) {
+            return Err(Error::BookingConflict);
+        }
 
-    fn get_car_status(env: &Env, owner: Address) -> Result<CarStatus, Error> {
-        public::get_car_status::get_car_status(env, &owner)
-    }
+        // The car must be back from its current rental before the booking starts
+        if has_rental(env, car_id) && read_rental(env, car_id)?.due_ts > start_ts {
+            return Err(Error::BookingConflict);
+        }
 
-    fn get_admin_available_to_withdraw(env: &Env) -> i128 {
-        public::get_admin_available_to_withdraw::get_admin_available_to_withdraw(env)
-    }
+        // Any started day is charged as a full day
+        let total_days: u32 = (end_ts - start_ts)
+            .div_ceil(SECONDS_PER_DAY)
+            .try_into()
+            .map_err(|_| Error::OverflowError)?;
+        let quote = quote_rental(env, &car, total_days, &token, read_fee_bearer(env, &car.owner))?;
+
+        let booking_id = next_booking_id(env)?;
+        insert_booking(
+            &mut bookings,
+            Booking {
+                booking_id,
+                renter: renter.clone(),
+                start_ts,
+                end_ts,
+                total_days,
+                amount: quote.base_price,
+                admin_commission: quote.admin_commission,
+                fee_bearer: quote.fee_bearer,
+                token: token.clone(),
+            },
+        );
+
+        // The prepayment stays in escrow until pickup, the deposit is collected at pickup
+        let prepaid = quote
+            .total
+            .checked_sub(quote.deposit)
+            .ok_or(Error::OverflowError)?;
+        let escrow_balance = read_escrow_balance(env, &token)
+            .checked_add(prepaid)
+            .ok_or(Error::OverflowError)?;
+
+        write_escrow_balance(env, &token, &escrow_balance);
+        write_bookings(env, car_id, &bookings);
+
+        token_transfer(env, &token, &renter, &env.current_contract_address(), &prepaid);
+        events::booking::reserved(env, renter, car_id, booking_id, token, start_ts, end_ts);
 
-    fn get_owner_available_to_withdraw(env: &Env, owner: Address) -> i128 {
-        get_owner_available_to_withdraw_public::get_owner_available_to_withdraw(env, &owner)
+        Ok(booking_id)
     }
 
-    fn rental(
-        env: &Env,
-        renter: Address,
-        owner: Address,
-        total_days_to_rent: u32,
-        amount: i128,
-    ) -> Result<(), Error> {
+    fn pickup(env: &Env, renter: Address, car_id: u32, booking_id: u32) -> Result<(), Error> {
         renter.require_auth();
 
-        if amount <= 0 {
-            return Err(Error::AmountMustBePositive);
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
         }
 
-        if total_days_to_rent == 0 {
-            return Err(Error::RentalDurationCannotBeZero);
+        let mut car = read_car(env, car_id)?;
+        let mut bookings = read_bookings(env, car_id);
+
+        let index = bookings
+            .iter()
+            .position(|booking| booking.booking_id == booking_id && booking.renter == renter)
+            .ok_or(Error::BookingNotFound)?;
+        let booking = bookings.get_unchecked(index as u32);
+
+        let now = env.ledger().timestamp();
+
+        if now < booking.start_ts {
+            return Err(Error::BookingNotStarted);
         }
 
-        if renter == owner {
-            return Err(Error::SelfRentalNotAllowed);
+        if now >= booking.end_ts {
+            return Err(Error::BookingExpired);
         }
 
-        if !has_car(env, &owner) {
-            return Err(Error::CarNotFound);
+        if car.car_status == CarStatus::Retired {
+            return Err(Error::CarRetired);
         }
 
-        let mut car = read_car(env, &owner)?;
+        if car.car_status == CarStatus::Maintenance {
+            return Err(Error::CarInMaintenance);
+        }
 
         if car.car_status != CarStatus::Available {
             return Err(Error::CarAlreadyRented);
         }
 
+        // The deposit of the previous return has to be settled before the car goes out again
+        release_expired_deposit_claim(env, car_id)?;
+
+        bookings.remove(index as u32);
+        release_booking_funds(env, &booking)?;
         car.car_status = CarStatus::Rented;
-        
-        let admin_commission = read_admin_commission(env);
-        let total_amount = amount
-            .checked_add(admin_commission)
+
+        let owner_share = booking
+            .fee_bearer
+            .owner_share(booking.amount, booking.admin_commission)?;
+
+        let mut balance = read_car_balance(env, car_id, &booking.token);
+        balance.pending = balance
+            .pending
+            .checked_add(owner_share)
             .ok_or(Error::OverflowError)?;
 
-        // Owner receives the full rental amount (without commission deduction)
-        car.available_to_withdraw = car
-            .available_to_withdraw
-            .checked_add(amount)
+        let rental = Rental {
+            renter: renter.clone(),
+            payer: Payer::Auth(renter.clone()),
+            total_days_to_rent: booking.total_days,
+            amount: booking.amount,
+            admin_commission: booking.admin_commission,
+            fee_bearer: booking.fee_bearer,
+            token: booking.token.clone(),
+            start_ts: now,
+            due_ts: booking.end_ts,
+            deposit: car.deposit,
+            deposit_withheld: 0,
+            withhold_reason: 0,
+            late_fee: car.late_fee,
+            late_fee_unit: car.late_fee_unit,
+        };
+
+        let escrow_balance = read_escrow_balance(env, &booking.token)
+            .checked_add(car.deposit)
             .ok_or(Error::OverflowError)?;
 
-        // Accumulate commission for admin
-        let mut admin_available = read_admin_available_to_withdraw(env);
-        admin_available = admin_available
-            .checked_add(admin_commission)
+        write_escrow_balance(env, &booking.token, &escrow_balance);
+        write_bookings(env, car_id, &bookings);
+        write_car_balance(env, car_id, &booking.token, &balance);
+        write_car(env, car_id, &car);
+        write_rental(env, car_id, &rental);
+
+        let fees_paid = booking
+            .amount
+            .checked_sub(owner_share)
             .ok_or(Error::OverflowError)?;
-        write_admin_available_to_withdraw(env, admin_available);
+        record_earnings(env, car_id, &car.owner, &booking.token, 1, owner_share, fees_paid)?;
 
-        let rental = Rental {
-            total_days_to_rent,
-            amount,
+        if car.deposit > 0 {
+            token_transfer(env, &booking.token, &renter, &env.current_contract_address(), &car.deposit);
+        }
+        events::booking::picked_up(env, renter.clone(), car_id, booking_id, booking.end_ts);
+
+        if car.deposit > 0 {
+            events::deposit::deposit_held(env, renter, car_id, car.deposit);
+        }
+        Ok(())
+    }
+
+    fn cancel_reservation(env: &Env, renter: Address, car_id: u32, booking_id: u32) -> Result<(), Error> {
+        renter.require_auth();
+
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut bookings = read_bookings(env, car_id);
+
+        let index = bookings
+            .iter()
+            .position(|booking| booking.booking_id == booking_id && booking.renter == renter)
+            .ok_or(Error::BookingNotFound)?;
+        let booking = bookings.get_unchecked(index as u32);
+
+        let now = env.ledger().timestamp();
+
+        if now >= booking.end_ts {
+            return Err(Error::BookingExpired);
+        }
+
+        let owner_share = booking
+            .fee_bearer
+            .owner_share(booking.amount, booking.admin_commission)?;
+
+        // The renter is not penalized when the car is still out or held for a deposit claim once the window has started
+        let car_unavailable = now >= booking.start_ts
+            && (read_car(env, car_id)?.car_status != CarStatus::Available || read_car_available_since(env, car_id) > now);
+        let (amount_refund, commission_refund) = if car_unavailable {
+            (owner_share, booking.admin_commission)
+        } else {
+            compute_cancellation_refund(env, car_id, booking.start_ts, owner_share, booking.admin_commission)?
         };
+        let refund = amount_refund
+            .checked_add(commission_refund)
+            .ok_or(Error::OverflowError)?;
 
-        let mut contract_balance = read_contract_balance(&env);
-        // Contract balance includes both the rental amount and commission
-        contract_balance = contract_balance
-            .checked_add(total_amount)
+        // The prepayment leaves escrow, whatever is not refunded is kept by the owner and the admin
+        bookings.remove(index as u32);
+        release_booking_funds(env, &booking)?;
+
+        let mut balance = read_car_balance(env, car_id, &booking.token);
+        balance.settled = balance
+            .settled
+            .checked_add(owner_share)
+            .and_then(|settled| settled.checked_sub(amount_refund))
+            .ok_or(Error::OverflowError)?;
+
+        let admin_available = read_admin_available_to_withdraw(env, &booking.token)
+            .checked_sub(commission_refund)
+            .ok_or(Error::OverflowError)?;
+        let contract_balance = read_contract_balance(env, &booking.token)
+            .checked_sub(refund)
             .ok_or(Error::OverflowError)?;
 
-        write_contract_balance(&env, &contract_balance);
-        write_car(env, &owner, &car);
-        write_rental(env, &renter, &owner, &rental);
+        write_admin_available_to_withdraw(env, &booking.token, admin_available);
+        write_contract_balance(env, &booking.token, &contract_balance);
+        write_bookings(env, car_id, &bookings);
+        write_car_balance(env, car_id, &booking.token, &balance);
 
-        // Renter pays the rental amount plus commission
-        token_transfer(&env, &renter, &env.current_contract_address(), &total_amount)?;
-        events::rental::rented(env, renter, owner, total_days_to_rent, amount);
+        let owner = read_car(env, car_id)?.owner;
+        let entry = EarningsStatement {
+            total_earned: owner_share,
+            total_refunded: amount_refund,
+            fees_paid: booking.amount.checked_sub(owner_share).ok_or(Error::OverflowError)?,
+            ..EarningsStatement::empty()
+        };
+        record_statement(env, car_id, &owner, &booking.token, entry)?;
+
+        if refund > 0 {
+            token_transfer(env, &booking.token, &env.current_contract_address(), &renter, &refund);
+        }
+        events::cancellation::reservation_cancelled(env, renter, car_id, booking_id, refund);
         Ok(())
     }
-    fn remove_car(env: &Env, owner: Address) -> Result<(), Error> {
+
+    fn get_bookings(env: &Env, car_id: u32, from: u64, to: u64) -> Result<Vec<Booking>, Error> {
+        public::get_bookings::get_bookings(env, car_id, from, to)
+    }
+
+    fn remove_car(env: &Env, car_id: u32) -> Result<(), Error> {
         let admin = read_admin(env)?;
         admin.require_auth();
 
-        if !has_car(env, &owner) {
+        if !has_car(env, car_id) {
             return Err(Error::CarNotFound);
         }
 
-        remove_car(env, &owner);
-        events::remove_car::car_removed(env, owner);
-        Ok(())
-    }
-    fn payout_owner(env: &Env, owner: Address, amount: i128) -> Result<(), Error> {
-        owner.require_auth();
+        let mut car = read_car(env, car_id)?;
 
-        if amount <= 0 {
-            return Err(Error::AmountMustBePositive);
+        if car.car_status == CarStatus::Retired {
+            return Err(Error::CarRetired);
+        }
+
+        if car.car_status == CarStatus::Rented || has_rental(env, car_id) {
+            return Err(Error::CarAlreadyRented);
+        }
+
+        if car.car_status == CarStatus::Maintenance {
+            return Err(Error::CarInMaintenance);
+        }
+
+        let bookings = settle_expired_bookings(env, car_id)?;
+        if !bookings.is_empty() {
+            return Err(Error::ActiveBookingsExist);
+        }
+        write_bookings(env, car_id, &bookings);
+
+        // The record stays so the owner can still withdraw, only the catalog entry goes away
+        car.car_status = CarStatus::Retired;
+
+        // Report what the owner can still withdraw in every token the car holds a balance in
+        let mut final_balance = Vec::new(env);
+        for token in read_car_balance_tokens(env, car_id).iter() {
+            let balance = read_car_balance(env, car_id, &token);
+
+            if balance.settled != 0 {
+                final_balance.push_back((token, balance.settled));
+            }
         }
 
-        if !has_car(env, &owner) {
+        write_car(env, car_id, &car);
+        remove_car_from_index(env, car_id);
+        events::remove_car::car_retired(env, car.owner, car_id, final_balance);
+        Ok(())
+    }
+    fn payout_owner(env: &Env, car_id: u32, token: Address, amount: i128) -> Result<(), Error> {
+        if !has_car(env, car_id) {
             return Err(Error::CarNotFound);
         }
 
-        let mut car = read_car(&env, &owner)?;
+        let car = read_car(env, car_id)?;
+        car.owner.require_auth();
 
-        // Owner can only withdraw when car is returned (Available)
-        if car.car_status != CarStatus::Available {
-            return Err(Error::CarAlreadyRented);
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
         }
 
-        if amount > car.available_to_withdraw {
+        let mut balance = read_car_balance(env, car_id, &token);
+
+        // Only settled earnings can be withdrawn, the current rental stays pending until the return
+        if amount > balance.settled {
             return Err(Error::InsufficientBalance);
         }
 
-        let mut contract_balance = read_contract_balance(&env);
+        let mut contract_balance = read_contract_balance(env, &token);
 
         if amount > contract_balance {
             return Err(Error::BalanceNotAvailableForAmountRequested);
         }
 
-        car.available_to_withdraw = car
-            .available_to_withdraw
+        balance.settled = balance
+            .settled
             .checked_sub(amount)
             .ok_or(Error::OverflowError)?;
         contract_balance = contract_balance
             .checked_sub(amount)
             .ok_or(Error::OverflowError)?;
 
-        write_car(&env, &owner, &car);
-        write_contract_balance(&env, &contract_balance);
+        write_car_balance(env, car_id, &token, &balance);
+        write_contract_balance(env, &token, &contract_balance);
+        record_withdrawal(env, car_id, &car.owner, &token, amount)?;
+
+        let destination = read_payout_settings(env, &car.owner).destination;
+
+        token_transfer(env, &token, &env.current_contract_address(), &destination, &amount);
+        events::payout_owner::payout_owner(env, car.owner, car_id, token, amount);
+        Ok(())
+    }
+
+    fn set_payout_settings(env: &Env, owner: Address, destination: Address, auto_payout: bool) -> Result<(), Error> {
+        owner.require_auth();
+
+        if destination == env.current_contract_address() {
+            return Err(Error::InvalidPayoutDestination);
+        }
+
+        let settings = PayoutSettings { destination, auto_payout };
+
+        write_payout_settings(env, &owner, &settings);
+        events::payout_owner::payout_settings_set(env, owner, settings);
+        Ok(())
+    }
+
+    fn get_payout_settings(env: &Env, owner: Address) -> PayoutSettings {
+        read_payout_settings(env, &owner)
+    }
+
+    fn set_driver_approval(env: &Env, payer: Address, renter: Address, approved: bool) -> Result<(), Error> {
+        payer.require_auth();
+
+        if approved {
+            write_driver_approval(env, &payer, &renter);
+        } else {
+            remove_driver_approval(env, &payer, &renter);
+        }
+
+        events::rental::driver_approval_set(env, payer, renter, approved);
+        Ok(())
+    }
+
+    fn is_driver_approved(env: &Env, payer: Address, renter: Address) -> bool {
+        has_driver_approval(env, &payer, &renter)
+    }
+
+    fn set_settlement_keeper(env: &Env, keeper: Option<Address>) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        match &keeper {
+            Some(keeper) => write_settlement_keeper(env, keeper),
+            None => remove_settlement_keeper(env),
+        }
 
-        token_transfer(&env, &env.current_contract_address(), &owner, &amount)?;
-        events::payout_owner::payout_owner(env, owner, amount);
+        events::payout_owner::settlement_keeper_set(env, keeper);
         Ok(())
     }
 
+    fn get_settlement_keeper(env: &Env) -> Option<Address> {
+        read_settlement_keeper(env)
+    }
+
+    fn settle_many(env: &Env, caller: Address, owners: Vec<Address>, cursor: SettleCursor, limit: u32) -> Result<Option<SettleCursor>, Error> {
+        caller.require_auth();
+
+        // Funds only move to the destination each owner registered, but the batch runs at the pace of the admin or its keeper
+        if caller != read_admin(env)? && read_settlement_keeper(env) != Some(caller) {
+            return Err(Error::Unauthorized);
+        }
+
+        if limit == 0 {
+            return Err(Error::InvalidPageLimit);
+        }
+
+        let tokens = read_allowed_tokens(env);
+        let mut budget = SettleBudget::new(limit, tokens.len());
+        let mut owner_index = cursor.owner;
+        let mut car_index = cursor.car;
+
+        while owner_index < owners.len() {
+            let next = SettleCursor { owner: owner_index, car: car_index };
+
+            if !budget.charge_owner() {
+                return next_settle_cursor(&cursor, next);
+            }
+
+            let owner = owners.get_unchecked(owner_index);
+            let settings = read_payout_settings(env, &owner);
+
+            if settings.auto_payout {
+                if !budget.charge_payout() {
+                    return next_settle_cursor(&cursor, next);
+                }
+
+                let car_ids = read_owner_cars(env, &owner);
+                let start = car_index.min(car_ids.len());
+                let end = start + budget.charge_cars(car_ids.len() - start);
+
+                if end > start {
+                    let payouts = settle_cars(env, &owner, &car_ids.slice(start..end), &tokens, &settings.destination)?;
+
+                    if !payouts.is_empty() {
+                        events::payout_owner::owner_settled(env, owner, settings.destination, payouts);
+                    }
+                }
+
+                // The cursor stays on this owner until all of their cars are settled
+                if end < car_ids.len() {
+                    return next_settle_cursor(&cursor, SettleCursor { owner: owner_index, car: end });
+                }
+            }
+
+            owner_index += 1;
+            car_index = 0;
+        }
+
+        Ok(None)
+    }
+
     fn set_admin_commission(env: &Env, commission: i128) -> Result<(), Error> {
         let admin = read_admin(env)?;
         admin.require_auth();
@@ -207,11 +1268,99 @@ impl RentACarContractTrait for RentACarContract {
             return Err(Error::AmountMustBePositive);
         }
 
-        write_admin_commission(env, commission);
+        write_commission_config(env, &CommissionConfig::flat(commission));
+        Ok(())
+    }
+
+    fn set_commission_config(env: &Env, config: CommissionConfig) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        config.validate()?;
+
+        write_commission_config(env, &config);
+        events::commission::commission_config_set(env, config);
+        Ok(())
+    }
+
+    fn get_commission_config(env: &Env) -> CommissionConfig {
+        read_commission_config(env)
+    }
+
+    fn set_owner_commission(env: &Env, owner: Address, config: CommissionConfig) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        config.validate()?;
+
+        write_owner_commission(env, &owner, &config);
+        events::commission::owner_commission_set(env, owner, config);
+        Ok(())
+    }
+
+    fn remove_owner_commission(env: &Env, owner: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        remove_owner_commission(env, &owner);
+        events::commission::owner_commission_removed(env, owner);
+        Ok(())
+    }
+
+    fn get_owner_commission(env: &Env, owner: Address) -> Option<CommissionConfig> {
+        read_owner_commission(env, &owner)
+    }
+
+    fn set_commission_tiers(env: &Env, tiers: Vec<CommissionTier>) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        validate_commission_tiers(&tiers)?;
+
+        write_commission_tiers(env, &tiers);
+        events::commission::commission_tiers_set(env, tiers);
+        Ok(())
+    }
+
+    fn get_commission_tiers(env: &Env) -> Vec<CommissionTier> {
+        read_commission_tiers(env)
+    }
+
+    fn get_owner_volume(env: &Env, owner: Address) -> OwnerVolume {
+        read_owner_volume(env, &owner)
+    }
+
+    fn get_owner_statement(env: &Env, owner: Address, token: Address) -> EarningsStatement {
+        public::get_statement::get_owner_statement(env, &owner, &token)
+    }
+
+    fn get_car_statement(env: &Env, car_id: u32, token: Address) -> Result<EarningsStatement, Error> {
+        public::get_statement::get_car_statement(env, car_id, &token)
+    }
+
+    fn set_fee_bearer(env: &Env, fee_bearer: FeeBearer) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        write_fee_bearer(env, &fee_bearer);
+        events::fee_bearer::fee_bearer_set(env, fee_bearer);
+        Ok(())
+    }
+
+    fn set_owner_fee_bearer(env: &Env, owner: Address, fee_bearer: FeeBearer) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        write_owner_fee_bearer(env, &owner, &fee_bearer);
+        events::fee_bearer::owner_fee_bearer_set(env, owner, fee_bearer);
         Ok(())
     }
 
-    fn withdraw_admin_commission(env: &Env, amount: i128) -> Result<(), Error> {
+    fn get_fee_bearer(env: &Env, owner: Address) -> FeeBearer {
+        read_fee_bearer(env, &owner)
+    }
+
+    fn withdraw_admin_commission(env: &Env, token: Address, amount: i128) -> Result<(), Error> {
         let admin = read_admin(env)?;
         admin.require_auth();
 
@@ -219,13 +1368,13 @@ impl RentACarContractTrait for RentACarContract {
             return Err(Error::AmountMustBePositive);
         }
 
-        let mut admin_available = read_admin_available_to_withdraw(env);
+        let mut admin_available = read_admin_available_to_withdraw(env, &token);
 
         if amount > admin_available {
             return Err(Error::InsufficientBalance);
         }
 
-        let mut contract_balance = read_contract_balance(&env);
+        let mut contract_balance = read_contract_balance(env, &token);
 
         if amount > contract_balance {
             return Err(Error::BalanceNotAvailableForAmountRequested);
@@ -238,40 +1387,196 @@ impl RentACarContractTrait for RentACarContract {
             .checked_sub(amount)
             .ok_or(Error::OverflowError)?;
 
-        write_admin_available_to_withdraw(env, admin_available);
-        write_contract_balance(&env, &contract_balance);
+        write_admin_available_to_withdraw(env, &token, admin_available);
+        write_contract_balance(env, &token, &contract_balance);
 
-        token_transfer(&env, &env.current_contract_address(), &admin, &amount)?;
+        token_transfer(env, &token, &env.current_contract_address(), &admin, &amount);
         Ok(())
     }
 
-    fn return_car(env: &Env, renter: Address, owner: Address) -> Result<(), Error> {
+    fn return_car(env: &Env, renter: Address, car_id: u32) -> Result<(), Error> {
         renter.require_auth();
 
-        if renter == owner {
-            return Err(Error::SelfRentalNotAllowed);
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
         }
 
-        if !has_car(env, &owner) {
-            return Err(Error::CarNotFound);
+        let mut car = read_car(env, car_id)?;
+
+        if renter == car.owner {
+            return Err(Error::SelfRentalNotAllowed);
         }
 
-        if !has_rental(env, &renter, &owner) {
+        if !has_rental(env, car_id) {
             return Err(Error::RentalNotFound);
         }
 
-        let mut car = read_car(env, &owner)?;
+        let rental = read_rental(env, car_id)?;
 
-        if car.car_status != CarStatus::Rented {
+        if rental.renter != renter || car.car_status != CarStatus::Rented {
             return Err(Error::RentalNotFound);
         }
 
         car.car_status = CarStatus::Available;
 
-        write_car(env, &owner, &car);
-        remove_rental(env, &renter, &owner);
+        let returned_ts = env.ledger().timestamp();
+        let late_penalty = compute_late_penalty(env, &rental, returned_ts)?;
+        let early_refund = compute_early_return_refund(env, car_id, &rental, returned_ts)?;
+
+        // Settle the deposit: the withheld part and the late penalty go to the owner, the rest back to the renter
+        let available_deposit = rental
+            .deposit
+            .checked_sub(rental.deposit_withheld)
+            .ok_or(Error::OverflowError)?;
+        let penalty_from_deposit = late_penalty.min(available_deposit);
+        let penalty_from_renter = late_penalty
+            .checked_sub(penalty_from_deposit)
+            .ok_or(Error::OverflowError)?;
+        let remaining_deposit = available_deposit
+            .checked_sub(penalty_from_deposit)
+            .ok_or(Error::OverflowError)?;
+
+        // The rest of the deposit stays in escrow so the owner can still claim damage found at the return
+        let claim_window = read_deposit_claim_window(env);
+        let (held_deposit, refund) = if claim_window > 0 {
+            (remaining_deposit, 0)
+        } else {
+            (0, remaining_deposit)
+        };
+
+        // Unused days refunded to the renter come out of the owner's earnings
+        let owner_credit = rental
+            .deposit_withheld
+            .checked_add(late_penalty)
+            .and_then(|credit| credit.checked_sub(early_refund))
+            .ok_or(Error::OverflowError)?;
+
+        // The rental amount is settled now that the car is back
+        let mut balance = read_car_balance(env, car_id, &rental.token);
+        let earnings = balance
+            .pending
+            .checked_add(owner_credit)
+            .ok_or(Error::OverflowError)?;
+        balance.settled = balance
+            .settled
+            .checked_add(balance.pending)
+            .ok_or(Error::OverflowError)?;
+        balance.pending = 0;
+
+        if owner_credit != 0 {
+            balance.settled = balance
+                .settled
+                .checked_add(owner_credit)
+                .ok_or(Error::OverflowError)?;
+
+            let contract_balance = read_contract_balance(env, &rental.token)
+                .checked_add(owner_credit)
+                .ok_or(Error::OverflowError)?;
+            write_contract_balance(env, &rental.token, &contract_balance);
+        }
+
+        // Owners on auto-payout receive this rental's earnings right away
+        let payout_settings = read_payout_settings(env, &car.owner);
+        let auto_payout = if payout_settings.auto_payout {
+            earnings.min(balance.settled).max(0)
+        } else {
+            0
+        };
+
+        if auto_payout > 0 {
+            balance.settled = balance
+                .settled
+                .checked_sub(auto_payout)
+                .ok_or(Error::OverflowError)?;
+
+            let contract_balance = read_contract_balance(env, &rental.token)
+                .checked_sub(auto_payout)
+                .ok_or(Error::OverflowError)?;
+            write_contract_balance(env, &rental.token, &contract_balance);
+        }
+
+        let escrow_balance = read_escrow_balance(env, &rental.token)
+            .checked_sub(rental.deposit)
+            .and_then(|escrow| escrow.checked_add(held_deposit))
+            .ok_or(Error::OverflowError)?;
+        write_escrow_balance(env, &rental.token, &escrow_balance);
+        write_car_balance(env, car_id, &rental.token, &balance);
+
+        let release_ts = returned_ts
+            .checked_add(claim_window)
+            .ok_or(Error::OverflowError)?;
+
+        if held_deposit > 0 {
+            let claim = DepositClaim {
+                renter: renter.clone(),
+                payer: rental.payer.address(),
+                token: rental.token.clone(),
+                amount: held_deposit,
+                release_ts,
+            };
+            write_deposit_claim(env, car_id, &claim);
+        }
+
+        let entry = EarningsStatement {
+            total_earned: rental.deposit_withheld.checked_add(late_penalty).ok_or(Error::OverflowError)?,
+            total_refunded: early_refund,
+            total_withdrawn: auto_payout,
+            ..EarningsStatement::empty()
+        };
+        record_statement(env, car_id, &car.owner, &rental.token, entry)?;
+
+        // Completed rentals count towards the volume tiers of the owner, revenue only in the reference token
+        let mut volume = read_owner_volume(env, &car.owner);
+        volume.completed_rentals = volume
+            .completed_rentals
+            .checked_add(1)
+            .ok_or(Error::OverflowError)?;
+
+        if rental.token == read_token(env)? {
+            volume.revenue = volume
+                .revenue
+                .checked_add(rental.amount)
+                .ok_or(Error::OverflowError)?;
+        }
+        write_owner_volume(env, &car.owner, &volume);
+
+        write_car(env, car_id, &car);
+        // A car held for a deposit claim only becomes available again once the window closes
+        let available_since = if held_deposit > 0 { release_ts } else { returned_ts };
+        write_car_available_since(env, car_id, available_since);
+        remove_rental(env, car_id);
+
+        // Whatever the deposit does not cover is pulled from the renter
+        if penalty_from_renter > 0 {
+            token_transfer(env, &rental.token, &renter, &env.current_contract_address(), &penalty_from_renter);
+        }
+
+        let total_refund = refund
+            .checked_add(early_refund)
+            .ok_or(Error::OverflowError)?;
+
+        // Refunds go back to whoever paid for the rental
+        if total_refund > 0 {
+            token_transfer(env, &rental.token, &env.current_contract_address(), &rental.payer.address(), &total_refund);
+        }
 
-        events::rental::car_returned(env, renter, owner);
+        if auto_payout > 0 {
+            token_transfer(env, &rental.token, &env.current_contract_address(), &payout_settings.destination, &auto_payout);
+        }
+
+        if refund > 0 {
+            events::deposit::deposit_refunded(env, renter.clone(), car_id, refund);
+        }
+
+        if held_deposit > 0 {
+            events::deposit::deposit_claim_opened(env, renter.clone(), car_id, held_deposit, release_ts);
+        }
+
+        events::rental::car_returned(env, &rental, car.owner.clone(), car_id, returned_ts, late_penalty, early_refund);
+
+        if auto_payout > 0 {
+            events::payout_owner::auto_payout(env, car.owner, car_id, payout_settings.destination, rental.token, auto_payout);
+        }
         Ok(())
     }
 }
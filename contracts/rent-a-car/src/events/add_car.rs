@@ -1,7 +1,7 @@
 ﻿use soroban_sdk::{Address, Env, Symbol};
 
-pub(crate) fn car_added(env: &Env, owner: Address, price_per_day: i128) {
-    let topics = (Symbol::new(env, "car_added"), owner.clone());
+pub(crate) fn car_added(env: &Env, owner: Address, car_id: u32, price_per_day: i128) {
+    let topics = (Symbol::new(env, "car_added"), owner.clone(), car_id);
 
     env.events().publish(
         topics,
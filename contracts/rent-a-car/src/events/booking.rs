@@ -0,0 +1,33 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn reserved(
+    env: &Env,
+    renter: Address,
+    car_id: u32,
+    booking_id: u32,
+    token: Address,
+    start_ts: u64,
+    end_ts: u64
+) {
+    let topics = (Symbol::new(env, "reserved"), renter.clone(), car_id);
+
+    env.events().publish(topics, (booking_id, token, start_ts, end_ts));
+}
+
+pub(crate) fn picked_up(env: &Env, renter: Address, car_id: u32, booking_id: u32, due_ts: u64) {
+    let topics = (Symbol::new(env, "picked_up"), renter.clone(), car_id);
+
+    env.events().publish(topics, (booking_id, due_ts));
+}
+
+pub(crate) fn booking_refunded(env: &Env, renter: Address, car_id: u32, booking_id: u32, refund: i128) {
+    let topics = (Symbol::new(env, "booking_refunded"), renter.clone(), car_id);
+
+    env.events().publish(topics, (booking_id, refund));
+}
+
+pub(crate) fn booking_forfeited(env: &Env, renter: Address, car_id: u32, booking_id: u32) {
+    let topics = (Symbol::new(env, "booking_forfeited"), renter.clone(), car_id);
+
+    env.events().publish(topics, booking_id);
+}
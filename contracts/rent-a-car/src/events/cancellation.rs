@@ -0,0 +1,32 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::structs::cancellation_policy::CancellationPolicy;
+
+pub(crate) fn cancellation_policy_set(env: &Env, policy: CancellationPolicy) {
+    let topics = (Symbol::new(env, "cancellation_policy_set"),);
+
+    env.events().publish(topics, policy);
+}
+
+pub(crate) fn car_cancellation_policy_set(
+    env: &Env,
+    owner: Address,
+    car_id: u32,
+    policy: CancellationPolicy
+) {
+    let topics = (Symbol::new(env, "car_cancellation_policy_set"), owner.clone(), car_id);
+
+    env.events().publish(topics, policy);
+}
+
+pub(crate) fn reservation_cancelled(
+    env: &Env,
+    renter: Address,
+    car_id: u32,
+    booking_id: u32,
+    refund: i128
+) {
+    let topics = (Symbol::new(env, "reservation_cancelled"), renter.clone(), car_id);
+
+    env.events().publish(topics, (booking_id, refund));
+}
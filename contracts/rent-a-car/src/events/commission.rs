@@ -0,0 +1,27 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::storage::structs::commission_config::{CommissionConfig, CommissionTier};
+
+pub(crate) fn commission_config_set(env: &Env, config: CommissionConfig) {
+    let topics = (Symbol::new(env, "commission_config_set"),);
+
+    env.events().publish(topics, config);
+}
+
+pub(crate) fn owner_commission_set(env: &Env, owner: Address, config: CommissionConfig) {
+    let topics = (Symbol::new(env, "owner_commission_set"), owner);
+
+    env.events().publish(topics, config);
+}
+
+pub(crate) fn owner_commission_removed(env: &Env, owner: Address) {
+    let topics = (Symbol::new(env, "owner_commission_removed"), owner);
+
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn commission_tiers_set(env: &Env, tiers: Vec<CommissionTier>) {
+    let topics = (Symbol::new(env, "commission_tiers_set"),);
+
+    env.events().publish(topics, tiers);
+}
@@ -0,0 +1,43 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn deposit_set(env: &Env, owner: Address, car_id: u32, deposit: i128) {
+    let topics = (Symbol::new(env, "deposit_set"), owner.clone(), car_id);
+
+    env.events().publish(topics, deposit);
+}
+
+pub(crate) fn deposit_held(env: &Env, renter: Address, car_id: u32, deposit: i128) {
+    let topics = (Symbol::new(env, "deposit_held"), renter.clone(), car_id);
+
+    env.events().publish(topics, deposit);
+}
+
+pub(crate) fn deposit_withheld(
+    env: &Env,
+    owner: Address,
+    car_id: u32,
+    amount: i128,
+    reason: u32
+) {
+    let topics = (Symbol::new(env, "deposit_withheld"), owner.clone(), car_id);
+
+    env.events().publish(topics, (amount, reason));
+}
+
+pub(crate) fn deposit_claim_opened(env: &Env, renter: Address, car_id: u32, amount: i128, release_ts: u64) {
+    let topics = (Symbol::new(env, "deposit_claim_opened"), renter.clone(), car_id);
+
+    env.events().publish(topics, (amount, release_ts));
+}
+
+pub(crate) fn deposit_claim_window_set(env: &Env, claim_window: u64) {
+    let topics = (Symbol::new(env, "deposit_claim_window_set"),);
+
+    env.events().publish(topics, claim_window);
+}
+
+pub(crate) fn deposit_refunded(env: &Env, renter: Address, car_id: u32, amount: i128) {
+    let topics = (Symbol::new(env, "deposit_refunded"), renter.clone(), car_id);
+
+    env.events().publish(topics, amount);
+}
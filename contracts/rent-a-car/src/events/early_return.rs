@@ -0,0 +1,9 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::structs::early_return_policy::EarlyReturnPolicy;
+
+pub(crate) fn early_return_policy_set(env: &Env, owner: Address, car_id: u32, policy: EarlyReturnPolicy) {
+    let topics = (Symbol::new(env, "early_return_policy_set"), owner.clone(), car_id);
+
+    env.events().publish(topics, policy);
+}
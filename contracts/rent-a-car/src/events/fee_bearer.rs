@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::types::fee_bearer::FeeBearer;
+
+pub(crate) fn fee_bearer_set(env: &Env, fee_bearer: FeeBearer) {
+    let topics = (Symbol::new(env, "fee_bearer_set"),);
+
+    env.events().publish(topics, fee_bearer);
+}
+
+pub(crate) fn owner_fee_bearer_set(env: &Env, owner: Address, fee_bearer: FeeBearer) {
+    let topics = (Symbol::new(env, "owner_fee_bearer_set"), owner);
+
+    env.events().publish(topics, fee_bearer);
+}
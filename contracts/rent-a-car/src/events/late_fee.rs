@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::types::late_fee_unit::LateFeeUnit;
+
+pub(crate) fn late_fee_set(env: &Env, owner: Address, car_id: u32, late_fee: i128, unit: LateFeeUnit) {
+    let topics = (Symbol::new(env, "late_fee_set"), owner.clone(), car_id);
+
+    env.events().publish(topics, (late_fee, unit));
+}
+
+pub(crate) fn late_grace_period_set(env: &Env, grace_period: u64) {
+    let topics = (Symbol::new(env, "late_grace_period_set"),);
+
+    env.events().publish(topics, grace_period);
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env, String, Symbol};
+
+pub(crate) fn maintenance_started(env: &Env, owner: Address, car_id: u32, reason: String) {
+    let topics = (Symbol::new(env, "maintenance_started"), owner.clone(), car_id);
+
+    env.events().publish(topics, reason);
+}
+
+pub(crate) fn maintenance_ended(env: &Env, owner: Address, car_id: u32, downtime: u64) {
+    let topics = (Symbol::new(env, "maintenance_ended"), owner.clone(), car_id);
+
+    env.events().publish(topics, downtime);
+}
@@ -1,5 +1,18 @@
-﻿pub mod contract;
+﻿#![allow(deprecated)]
+
+pub mod contract;
 pub mod remove_car;
 pub mod payout_owner;
 pub mod rental;
-pub mod add_car;
\ No newline at end of file
+pub mod add_car;
+pub mod update_car_details;
+pub mod deposit;
+pub mod late_fee;
+pub mod booking;
+pub mod cancellation;
+pub mod early_return;
+pub mod maintenance;
+pub mod commission;
+pub mod fee_bearer;
+pub mod token;
+pub mod statement;
\ No newline at end of file
@@ -1,10 +1,36 @@
-﻿use soroban_sdk::{Address, Env, Symbol};
+﻿use soroban_sdk::{Address, Env, Symbol, Vec};
 
-pub(crate) fn payout_owner(env: &Env, owner: Address, amount: i128) {
-    let topics = (Symbol::new(env, "payout"), owner.clone());
+use crate::storage::structs::payout_settings::PayoutSettings;
+
+pub(crate) fn payout_owner(env: &Env, owner: Address, car_id: u32, token: Address, amount: i128) {
+    let topics = (Symbol::new(env, "payout"), owner.clone(), car_id);
 
     env.events().publish(
         topics,
-        amount
+        (token, amount)
     );
-}
\ No newline at end of file
+}
+
+pub(crate) fn payout_settings_set(env: &Env, owner: Address, settings: PayoutSettings) {
+    let topics = (Symbol::new(env, "payout_settings_set"), owner);
+
+    env.events().publish(topics, (settings.destination, settings.auto_payout));
+}
+
+pub(crate) fn owner_settled(env: &Env, owner: Address, destination: Address, payouts: Vec<(Address, i128)>) {
+    let topics = (Symbol::new(env, "owner_settled"), owner);
+
+    env.events().publish(topics, (destination, payouts));
+}
+
+pub(crate) fn auto_payout(env: &Env, owner: Address, car_id: u32, destination: Address, token: Address, amount: i128) {
+    let topics = (Symbol::new(env, "auto_payout"), owner, car_id);
+
+    env.events().publish(topics, (destination, token, amount));
+}
+
+pub(crate) fn settlement_keeper_set(env: &Env, keeper: Option<Address>) {
+    let topics = (Symbol::new(env, "settlement_keeper_set"),);
+
+    env.events().publish(topics, keeper);
+}
@@ -1,10 +1,10 @@
-﻿use soroban_sdk::{Address, Env, Symbol};
+﻿use soroban_sdk::{Address, Env, Symbol, Vec};
 
-pub(crate) fn car_removed(env: &Env, owner: Address) {
-    let topics = (Symbol::new(env, "car_removed"), owner.clone());
+pub(crate) fn car_retired(env: &Env, owner: Address, car_id: u32, final_balance: Vec<(Address, i128)>) {
+    let topics = (Symbol::new(env, "car_retired"), owner.clone(), car_id);
 
     env.events().publish(
         topics,
-        ()
+        final_balance
     );
 }
\ No newline at end of file
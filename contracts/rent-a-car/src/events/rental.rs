@@ -1,26 +1,60 @@
 ﻿use soroban_sdk::{Address, Env, Symbol};
 
+use crate::storage::structs::rental::Rental;
+use crate::storage::structs::rental_quote::RentalQuote;
+
 pub(crate) fn rented(
     env: &Env,
     renter: Address,
     owner: Address,
+    car_id: u32,
+    token: Address,
     total_days: u32,
-    amount: i128
+    quote: &RentalQuote
 ) {
-    let topics = (Symbol::new(env, "rented"), renter.clone(), owner.clone());
+    let topics = (Symbol::new(env, "rented"), renter.clone(), owner.clone(), car_id);
 
     env.events().publish(
         topics,
-        (total_days, amount)
+        (token, total_days, quote.base_price, quote.owner_amount, quote.admin_commission)
     );
 }
 
-pub(crate) fn car_returned(
+pub(crate) fn rental_extended(
     env: &Env,
     renter: Address,
-    owner: Address
+    owner: Address,
+    car_id: u32,
+    extra_days: u32,
+    due_ts: u64,
+    amount: i128
+) {
+    let topics = (Symbol::new(env, "rental_extended"), renter.clone(), owner.clone(), car_id);
+
+    env.events().publish(topics, (extra_days, due_ts, amount));
+}
+
+pub(crate) fn car_returned(
+    env: &Env,
+    rental: &Rental,
+    owner: Address,
+    car_id: u32,
+    returned_ts: u64,
+    late_penalty: i128,
+    early_refund: i128
 ) {
-    let topics = (Symbol::new(env, "car_returned"), renter.clone(), owner.clone());
+    let topics = (Symbol::new(env, "car_returned"), rental.renter.clone(), owner.clone(), car_id);
+
+    env.events().publish(topics, (rental.token.clone(), returned_ts, late_penalty, early_refund));
+}
+pub(crate) fn rental_paid_by(env: &Env, payer: Address, renter: Address, car_id: u32, amount: i128) {
+    let topics = (Symbol::new(env, "rental_paid_by"), payer, renter, car_id);
 
-    env.events().publish(topics, ());
-}
\ No newline at end of file
+    env.events().publish(topics, amount);
+}
+
+pub(crate) fn driver_approval_set(env: &Env, payer: Address, renter: Address, approved: bool) {
+    let topics = (Symbol::new(env, "driver_approval_set"), payer, renter);
+
+    env.events().publish(topics, approved);
+}
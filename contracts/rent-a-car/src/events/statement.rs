@@ -0,0 +1,9 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::structs::earnings_statement::EarningsStatement;
+
+pub(crate) fn statement_entry(env: &Env, owner: Address, car_id: u32, token: Address, entry: EarningsStatement) {
+    let topics = (Symbol::new(env, "statement_entry"), owner, car_id);
+
+    env.events().publish(topics, (token, entry));
+}
@@ -0,0 +1,31 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+pub(crate) fn token_allowed(env: &Env, token: Address) {
+    let topics = (Symbol::new(env, "token_allowed"), token);
+
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn token_disallowed(env: &Env, token: Address) {
+    let topics = (Symbol::new(env, "token_disallowed"), token);
+
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn token_migrated(env: &Env, old_token: Address, new_token: Address) {
+    let topics = (Symbol::new(env, "token_migrated"), old_token, new_token);
+
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn surplus_swept(env: &Env, token: Address, to: Address, amount: i128) {
+    let topics = (Symbol::new(env, "surplus_swept"), token, to);
+
+    env.events().publish(topics, amount);
+}
+
+pub(crate) fn car_tokens_set(env: &Env, owner: Address, car_id: u32, tokens: Vec<Address>) {
+    let topics = (Symbol::new(env, "car_tokens_set"), owner, car_id);
+
+    env.events().publish(topics, tokens);
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::structs::car_details::CarDetails;
+
+pub(crate) fn car_details_updated(env: &Env, owner: Address, car_id: u32, details: CarDetails) {
+    let topics = (Symbol::new(env, "car_details_updated"), owner.clone(), car_id);
+
+    env.events().publish(
+        topics,
+        details
+    );
+}
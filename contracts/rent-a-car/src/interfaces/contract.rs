@@ -1,23 +1,107 @@
-﻿use soroban_sdk::{Address, Env};
+﻿use soroban_sdk::{Address, Env, String, Vec};
 
-use crate::storage::types::{car_status::CarStatus, errors::Error};
+use crate::storage::structs::{booking::Booking, cancellation_policy::CancellationPolicy, car::Car, car_details::CarDetails, car_filter::CarFilter, car_page::CarPage, commission_config::{CommissionConfig, CommissionTier}, deposit_claim::DepositClaim, early_return_policy::EarlyReturnPolicy, earnings_statement::EarningsStatement, maintenance_record::MaintenanceRecord, owner_balance::OwnerBalance, owner_volume::OwnerVolume, payout_settings::PayoutSettings, reconciliation::Reconciliation, rental::Rental, rental_quote::RentalQuote, rental_time_status::RentalTimeStatus, settle_cursor::SettleCursor};
+use crate::storage::types::{car_status::CarStatus, errors::Error, fee_bearer::FeeBearer, late_fee_unit::LateFeeUnit, payer::Payer};
 
 pub trait RentACarContractTrait {
     fn __constructor(env: &Env, admin: Address, token: Address) -> Result<(), Error>;
-    fn add_car(env: &Env, owner: Address, price_per_day: i128) -> Result<(), Error>;
-    fn get_car_status(env: &Env, owener: Address) -> Result<CarStatus, Error>;
+    fn add_car(
+        env: &Env,
+        owner: Address,
+        price_per_day: i128,
+        details: CarDetails,
+    ) -> Result<u32, Error>;
+    fn update_car_details(
+        env: &Env,
+        caller: Address,
+        car_id: u32,
+        details: CarDetails,
+    ) -> Result<(), Error>;
+    fn start_maintenance(env: &Env, caller: Address, car_id: u32, reason: String) -> Result<(), Error>;
+    fn end_maintenance(env: &Env, caller: Address, car_id: u32) -> Result<(), Error>;
+    fn set_car_deposit(env: &Env, car_id: u32, deposit: i128) -> Result<(), Error>;
+    fn withhold_deposit(env: &Env, car_id: u32, amount: i128, reason: u32) -> Result<(), Error>;
+    fn release_deposit(env: &Env, car_id: u32) -> Result<(), Error>;
+    fn get_deposit_claim(env: &Env, car_id: u32) -> Result<DepositClaim, Error>;
+    fn set_deposit_claim_window(env: &Env, claim_window: u64) -> Result<(), Error>;
+    fn set_car_late_fee(env: &Env, car_id: u32, late_fee: i128, unit: LateFeeUnit) -> Result<(), Error>;
+    fn set_car_early_return_policy(env: &Env, car_id: u32, policy: EarlyReturnPolicy) -> Result<(), Error>;
+    fn set_late_grace_period(env: &Env, grace_period: u64) -> Result<(), Error>;
+    fn set_cancellation_policy(env: &Env, policy: CancellationPolicy) -> Result<(), Error>;
+    fn set_car_cancellation_policy(env: &Env, car_id: u32, policy: CancellationPolicy) -> Result<(), Error>;
+    fn get_cancellation_policy(env: &Env, car_id: u32) -> Result<CancellationPolicy, Error>;
+    fn get_maintenance_log(env: &Env, car_id: u32) -> Result<Vec<MaintenanceRecord>, Error>;
+    fn get_maintenance_downtime(env: &Env, car_id: u32) -> Result<u64, Error>;
+    fn get_car(env: &Env, car_id: u32) -> Result<Car, Error>;
+    fn get_car_balance(env: &Env, car_id: u32, token: Address) -> Result<OwnerBalance, Error>;
+    fn get_car_tokens(env: &Env, car_id: u32) -> Result<Vec<Address>, Error>;
+    fn set_car_tokens(env: &Env, car_id: u32, tokens: Vec<Address>) -> Result<(), Error>;
+    fn allow_token(env: &Env, token: Address) -> Result<(), Error>;
+    fn disallow_token(env: &Env, token: Address) -> Result<(), Error>;
+    fn get_allowed_tokens(env: &Env) -> Vec<Address>;
+    fn migrate_token(env: &Env, new_token: Address) -> Result<(), Error>;
+    fn list_cars(env: &Env, cursor: u32, limit: u32, filter: CarFilter) -> Result<CarPage, Error>;
+    fn get_car_status(env: &Env, car_id: u32) -> Result<CarStatus, Error>;
+    fn get_owner_cars(env: &Env, owner: Address) -> Vec<u32>;
+    fn get_quote(env: &Env, car_id: u32, total_days_to_rent: u32) -> Result<RentalQuote, Error>;
+    fn get_rental(env: &Env, car_id: u32) -> Result<Rental, Error>;
+    fn get_rental_time_status(env: &Env, car_id: u32) -> Result<RentalTimeStatus, Error>;
     fn rental(
         env: &Env,
         renter: Address,
-        car_owner: Address,
+        car_id: u32,
+        total_days_to_rent: u32,
+        amount: i128,
+        token: Address,
+    ) -> Result<(), Error>;
+    fn rental_paid_by(
+        env: &Env,
+        renter: Address,
+        car_id: u32,
         total_days_to_rent: u32,
         amount: i128,
+        token: Address,
+        payer: Payer,
     ) -> Result<(), Error>;
-    fn remove_car(env: &Env, owner: Address) -> Result<(), Error>;
-    fn payout_owner(env: &Env, owner: Address, amount: i128) -> Result<(), Error>;
+    fn extend_rental(env: &Env, renter: Address, car_id: u32, extra_days: u32) -> Result<(), Error>;
+    fn reserve(
+        env: &Env,
+        renter: Address,
+        car_id: u32,
+        start_ts: u64,
+        end_ts: u64,
+        token: Address,
+    ) -> Result<u32, Error>;
+    fn pickup(env: &Env, renter: Address, car_id: u32, booking_id: u32) -> Result<(), Error>;
+    fn cancel_reservation(env: &Env, renter: Address, car_id: u32, booking_id: u32) -> Result<(), Error>;
+    fn get_bookings(env: &Env, car_id: u32, from: u64, to: u64) -> Result<Vec<Booking>, Error>;
+    fn remove_car(env: &Env, car_id: u32) -> Result<(), Error>;
+    fn payout_owner(env: &Env, car_id: u32, token: Address, amount: i128) -> Result<(), Error>;
+    fn set_payout_settings(env: &Env, owner: Address, destination: Address, auto_payout: bool) -> Result<(), Error>;
+    fn get_payout_settings(env: &Env, owner: Address) -> PayoutSettings;
+    fn set_driver_approval(env: &Env, payer: Address, renter: Address, approved: bool) -> Result<(), Error>;
+    fn is_driver_approved(env: &Env, payer: Address, renter: Address) -> bool;
+    fn set_settlement_keeper(env: &Env, keeper: Option<Address>) -> Result<(), Error>;
+    fn get_settlement_keeper(env: &Env) -> Option<Address>;
+    fn settle_many(env: &Env, caller: Address, owners: Vec<Address>, cursor: SettleCursor, limit: u32) -> Result<Option<SettleCursor>, Error>;
     fn set_admin_commission(env: &Env, commission: i128) -> Result<(), Error>;
-    fn withdraw_admin_commission(env: &Env, amount: i128) -> Result<(), Error>;
-    fn get_admin_available_to_withdraw(env: &Env) -> i128;
-    fn get_owner_available_to_withdraw(env: &Env, owner: Address) -> i128;
-    fn return_car(env: &Env, renter: Address, owner: Address) -> Result<(), Error>;
+    fn set_commission_config(env: &Env, config: CommissionConfig) -> Result<(), Error>;
+    fn get_commission_config(env: &Env) -> CommissionConfig;
+    fn set_owner_commission(env: &Env, owner: Address, config: CommissionConfig) -> Result<(), Error>;
+    fn remove_owner_commission(env: &Env, owner: Address) -> Result<(), Error>;
+    fn get_owner_commission(env: &Env, owner: Address) -> Option<CommissionConfig>;
+    fn set_commission_tiers(env: &Env, tiers: Vec<CommissionTier>) -> Result<(), Error>;
+    fn get_commission_tiers(env: &Env) -> Vec<CommissionTier>;
+    fn get_owner_volume(env: &Env, owner: Address) -> OwnerVolume;
+    fn get_owner_statement(env: &Env, owner: Address, token: Address) -> EarningsStatement;
+    fn get_car_statement(env: &Env, car_id: u32, token: Address) -> Result<EarningsStatement, Error>;
+    fn set_fee_bearer(env: &Env, fee_bearer: FeeBearer) -> Result<(), Error>;
+    fn set_owner_fee_bearer(env: &Env, owner: Address, fee_bearer: FeeBearer) -> Result<(), Error>;
+    fn get_fee_bearer(env: &Env, owner: Address) -> FeeBearer;
+    fn withdraw_admin_commission(env: &Env, token: Address, amount: i128) -> Result<(), Error>;
+    fn get_admin_available_to_withdraw(env: &Env, token: Address) -> i128;
+    fn reconcile(env: &Env, token: Address) -> Reconciliation;
+    fn sweep_surplus(env: &Env, token: Address, to: Address) -> Result<i128, Error>;
+    fn get_owner_available_to_withdraw(env: &Env, owner: Address, token: Address) -> OwnerBalance;
+    fn return_car(env: &Env, renter: Address, car_id: u32) -> Result<(), Error>;
 }
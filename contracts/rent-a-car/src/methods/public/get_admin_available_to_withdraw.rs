@@ -1,7 +1,7 @@
-use soroban_sdk::Env;
+use soroban_sdk::{Address, Env};
 use crate::storage::admin::read_admin_available_to_withdraw;
 
-pub(crate) fn get_admin_available_to_withdraw(env: &Env) -> i128 {
-    read_admin_available_to_withdraw(env)
+pub(crate) fn get_admin_available_to_withdraw(env: &Env, token: &Address) -> i128 {
+    read_admin_available_to_withdraw(env, token)
 }
 
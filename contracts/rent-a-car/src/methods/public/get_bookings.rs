@@ -0,0 +1,25 @@
+use soroban_sdk::{Env, Vec};
+use crate::storage::booking::read_bookings;
+use crate::storage::car::has_car;
+use crate::storage::structs::booking::Booking;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn get_bookings(env: &Env, car_id: u32, from: u64, to: u64) -> Result<Vec<Booking>, Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    if to <= from {
+        return Err(Error::InvalidBookingRange);
+    }
+
+    let mut bookings = Vec::new(env);
+
+    for booking in read_bookings(env, car_id).iter() {
+        if booking.overlaps(from, to) {
+            bookings.push_back(booking);
+        }
+    }
+
+    Ok(bookings)
+}
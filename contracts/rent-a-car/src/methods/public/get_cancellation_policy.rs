@@ -0,0 +1,13 @@
+use soroban_sdk::Env;
+use crate::storage::cancellation_policy::read_cancellation_policy;
+use crate::storage::car::has_car;
+use crate::storage::structs::cancellation_policy::CancellationPolicy;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn get_cancellation_policy(env: &Env, car_id: u32) -> Result<CancellationPolicy, Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    Ok(read_cancellation_policy(env, car_id))
+}
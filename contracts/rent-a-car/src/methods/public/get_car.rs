@@ -0,0 +1,29 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::storage::car::{has_car, read_car, read_car_balance};
+use crate::storage::structs::{car::Car, owner_balance::OwnerBalance};
+use crate::storage::token::read_car_tokens;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn get_car(env: &Env, car_id: u32) -> Result<Car, Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    read_car(env, car_id)
+}
+
+pub(crate) fn get_car_balance(env: &Env, car_id: u32, token: Address) -> Result<OwnerBalance, Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    Ok(read_car_balance(env, car_id, &token))
+}
+
+pub(crate) fn get_car_tokens(env: &Env, car_id: u32) -> Result<Vec<Address>, Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    read_car_tokens(env, car_id)
+}
@@ -0,0 +1,29 @@
+use soroban_sdk::{Env, Vec};
+use crate::storage::car::has_car;
+use crate::storage::maintenance::read_maintenance_log;
+use crate::storage::structs::maintenance_record::MaintenanceRecord;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn get_maintenance_log(env: &Env, car_id: u32) -> Result<Vec<MaintenanceRecord>, Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    Ok(read_maintenance_log(env, car_id))
+}
+
+/// Total seconds the car spent in maintenance, an open entry counts up to now.
+pub(crate) fn get_maintenance_downtime(env: &Env, car_id: u32) -> Result<u64, Error> {
+    let now = env.ledger().timestamp();
+    let mut downtime: u64 = 0;
+
+    for record in get_maintenance_log(env, car_id)?.iter() {
+        let end_ts = if record.end_ts == 0 { now } else { record.end_ts };
+
+        downtime = downtime
+            .checked_add(end_ts.saturating_sub(record.start_ts))
+            .ok_or(Error::OverflowError)?;
+    }
+
+    Ok(downtime)
+}
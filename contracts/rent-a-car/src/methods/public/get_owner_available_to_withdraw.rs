@@ -1,25 +1,17 @@
 use soroban_sdk::{Address, Env};
-use crate::storage::car::{has_car, read_car};
-use crate::storage::types::car_status::CarStatus;
+use crate::storage::car::{read_car_balance, read_owner_cars};
+use crate::storage::structs::owner_balance::OwnerBalance;
 
-pub(crate) fn get_owner_available_to_withdraw(env: &Env, owner: &Address) -> i128 {
-    // If car doesn't exist, return 0 (cannot withdraw)
-    if !has_car(env, owner) {
-        return 0;
-    }
+pub(crate) fn get_owner_available_to_withdraw(env: &Env, owner: &Address, token: &Address) -> OwnerBalance {
+    let mut balance = OwnerBalance { pending: 0, settled: 0 };
 
-    // Try to read car, if fails return 0
-    let car = match read_car(env, owner) {
-        Ok(c) => c,
-        Err(_) => return 0,
-    };
+    for car_id in read_owner_cars(env, owner).iter() {
+        let car_balance = read_car_balance(env, car_id, token);
 
-    // Owner can only withdraw when car is returned (Available)
-    if car.car_status != CarStatus::Available {
-        return 0;
+        // Settled earnings can be withdrawn in any status, pending ones wait for the return
+        balance.pending = balance.pending.saturating_add(car_balance.pending);
+        balance.settled = balance.settled.saturating_add(car_balance.settled);
     }
 
-    // Return the available amount to withdraw
-    car.available_to_withdraw
+    balance
 }
-
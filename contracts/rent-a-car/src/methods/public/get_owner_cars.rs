@@ -0,0 +1,6 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::storage::car::read_owner_cars;
+
+pub(crate) fn get_owner_cars(env: &Env, owner: &Address) -> Vec<u32> {
+    read_owner_cars(env, owner)
+}
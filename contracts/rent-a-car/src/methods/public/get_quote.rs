@@ -0,0 +1,58 @@
+use soroban_sdk::{Address, Env};
+use crate::methods::rental::commission::compute_commission;
+use crate::storage::car::{has_car, read_car};
+use crate::storage::fee_bearer::read_fee_bearer;
+use crate::storage::structs::car::Car;
+use crate::storage::structs::rental_quote::RentalQuote;
+use crate::storage::token::read_token;
+use crate::storage::types::{errors::Error, fee_bearer::FeeBearer};
+
+pub(crate) fn quote_rental(
+    env: &Env,
+    car: &Car,
+    total_days_to_rent: u32,
+    token: &Address,
+    fee_bearer: FeeBearer,
+) -> Result<RentalQuote, Error> {
+    if total_days_to_rent == 0 {
+        return Err(Error::RentalDurationCannotBeZero);
+    }
+
+    let base_price = car
+        .price_per_day
+        .checked_mul(total_days_to_rent as i128)
+        .ok_or(Error::OverflowError)?;
+
+    let mut admin_commission = compute_commission(env, &car.owner, token, base_price)?;
+
+    // A commission taken from the owner can never exceed the rental amount
+    if fee_bearer == FeeBearer::Owner {
+        admin_commission = admin_commission.min(base_price);
+    }
+
+    let owner_amount = fee_bearer.owner_share(base_price, admin_commission)?;
+    let total = fee_bearer
+        .renter_charge(base_price, admin_commission)?
+        .checked_add(car.deposit)
+        .ok_or(Error::OverflowError)?;
+
+    Ok(RentalQuote {
+        base_price,
+        admin_commission,
+        fee_bearer,
+        owner_amount,
+        deposit: car.deposit,
+        total,
+    })
+}
+
+pub(crate) fn get_quote(env: &Env, car_id: u32, total_days_to_rent: u32) -> Result<RentalQuote, Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    let car = read_car(env, car_id)?;
+
+    // Quotes are in the reference token
+    quote_rental(env, &car, total_days_to_rent, &read_token(env)?, read_fee_bearer(env, &car.owner))
+}
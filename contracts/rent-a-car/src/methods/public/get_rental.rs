@@ -0,0 +1,37 @@
+use soroban_sdk::Env;
+use crate::storage::car::has_car;
+use crate::storage::deposit_claim::read_deposit_claim;
+use crate::storage::rental::{has_rental, read_rental};
+use crate::storage::structs::deposit_claim::DepositClaim;
+use crate::storage::structs::rental::Rental;
+use crate::storage::structs::rental_time_status::RentalTimeStatus;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn get_rental(env: &Env, car_id: u32) -> Result<Rental, Error> {
+    if !has_rental(env, car_id) {
+        return Err(Error::RentalNotFound);
+    }
+
+    read_rental(env, car_id)
+}
+
+pub(crate) fn get_rental_time_status(env: &Env, car_id: u32) -> Result<RentalTimeStatus, Error> {
+    let rental = get_rental(env, car_id)?;
+    let now = env.ledger().timestamp();
+
+    Ok(RentalTimeStatus {
+        start_ts: rental.start_ts,
+        due_ts: rental.due_ts,
+        remaining_secs: rental.due_ts.saturating_sub(now),
+        overdue: now > rental.due_ts,
+        overdue_secs: now.saturating_sub(rental.due_ts),
+    })
+}
+
+pub(crate) fn get_deposit_claim(env: &Env, car_id: u32) -> Result<DepositClaim, Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    read_deposit_claim(env, car_id)
+}
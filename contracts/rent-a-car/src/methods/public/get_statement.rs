@@ -0,0 +1,17 @@
+use soroban_sdk::{Address, Env};
+use crate::storage::car::has_car;
+use crate::storage::statement::{read_car_statement, read_owner_statement};
+use crate::storage::structs::earnings_statement::EarningsStatement;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn get_owner_statement(env: &Env, owner: &Address, token: &Address) -> EarningsStatement {
+    read_owner_statement(env, owner, token)
+}
+
+pub(crate) fn get_car_statement(env: &Env, car_id: u32, token: &Address) -> Result<EarningsStatement, Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    Ok(read_car_statement(env, car_id, token))
+}
@@ -0,0 +1,81 @@
+use soroban_sdk::{Env, Vec};
+use crate::storage::car::{car_index_chunk, read_car, read_car_index, read_car_index_chunks, read_owner_cars, CAR_INDEX_CHUNK};
+use crate::storage::structs::car_filter::CarFilter;
+use crate::storage::structs::car_page::{CarPage, CarRecord};
+use crate::storage::types::errors::Error;
+
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Storage entries a single call may read, so a selective filter cannot walk the whole fleet.
+pub const MAX_SCAN: u32 = 100;
+
+pub(crate) fn list_cars(
+    env: &Env,
+    cursor: u32,
+    limit: u32,
+    filter: &CarFilter,
+) -> Result<CarPage, Error> {
+    if limit == 0 {
+        return Err(Error::InvalidPageLimit);
+    }
+
+    let limit = limit.min(MAX_PAGE_SIZE);
+
+    let mut page = CarPage { cars: Vec::new(env), next_cursor: None };
+    let mut scanned = 0;
+
+    // Filtering by owner only needs to walk that owner's cars
+    if let Some(owner) = &filter.owner {
+        scan_cars(env, &read_owner_cars(env, owner), cursor, limit, filter, &mut page, &mut scanned);
+        return Ok(page);
+    }
+
+    // Chunks before the cursor are never read
+    for chunk in car_index_chunk(cursor)..read_car_index_chunks(env) {
+        if page.cars.len() == limit || scanned == MAX_SCAN {
+            page.next_cursor = Some(chunk * CAR_INDEX_CHUNK);
+            break;
+        }
+
+        scanned += 1;
+
+        if scan_cars(env, &read_car_index(env, chunk), cursor, limit, filter, &mut page, &mut scanned) {
+            break;
+        }
+    }
+
+    Ok(page)
+}
+
+/// Adds the matching cars from `car_ids`, returns true once the page is full or the scan budget is spent.
+fn scan_cars(
+    env: &Env,
+    car_ids: &Vec<u32>,
+    cursor: u32,
+    limit: u32,
+    filter: &CarFilter,
+    page: &mut CarPage,
+    scanned: &mut u32,
+) -> bool {
+    // Car IDs are assigned incrementally, so both indexes are sorted
+    for car_id in car_ids.iter().filter(|car_id| *car_id >= cursor) {
+        // The cursor points at the first car not scanned yet
+        if page.cars.len() == limit || *scanned == MAX_SCAN {
+            page.next_cursor = Some(car_id);
+            return true;
+        }
+
+        *scanned += 1;
+
+        let car = match read_car(env, car_id) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+
+        if filter.matches(&car) {
+            page.cars.push_back(CarRecord { car_id, car });
+        }
+    }
+
+    false
+}
@@ -1,3 +1,13 @@
 ﻿pub mod get_car_status;
 pub mod get_admin_available_to_withdraw;
-pub mod get_owner_available_to_withdraw;
\ No newline at end of file
+pub mod get_owner_available_to_withdraw;
+pub mod get_owner_cars;
+pub mod get_car;
+pub mod list_cars;
+pub mod get_quote;
+pub mod get_rental;
+pub mod get_bookings;
+pub mod get_cancellation_policy;
+pub mod get_maintenance_log;
+pub mod reconcile;
+pub mod get_statement;
\ No newline at end of file
@@ -0,0 +1,30 @@
+use soroban_sdk::{token, Address, Env};
+use crate::storage::admin::read_admin_available_to_withdraw;
+use crate::storage::car::read_owner_liabilities;
+use crate::storage::contract_balance::read_contract_balance;
+use crate::storage::escrow::read_escrow_balance;
+use crate::storage::structs::reconciliation::Reconciliation;
+
+pub(crate) fn reconcile(env: &Env, token: &Address) -> Reconciliation {
+    let book_balance = read_contract_balance(env, token);
+    let actual_balance = token::TokenClient::new(env, token).balance(&env.current_contract_address());
+
+    let owner_liabilities = read_owner_liabilities(env, token);
+    let admin_liabilities = read_admin_available_to_withdraw(env, token);
+    let escrow_liabilities = read_escrow_balance(env, token);
+
+    // Whichever of the counters is higher is owed, so a drift between them never counts as surplus
+    let liabilities = owner_liabilities
+        .saturating_add(admin_liabilities)
+        .max(book_balance)
+        .saturating_add(escrow_liabilities);
+
+    Reconciliation {
+        book_balance,
+        actual_balance,
+        owner_liabilities,
+        admin_liabilities,
+        escrow_liabilities,
+        surplus: actual_balance.saturating_sub(liabilities),
+    }
+}
@@ -0,0 +1,113 @@
+use soroban_sdk::{Env, Vec};
+use crate::events;
+use crate::storage::admin::{read_admin_available_to_withdraw, write_admin_available_to_withdraw};
+use crate::storage::booking::read_bookings;
+use crate::methods::rental::statement::record_earnings;
+use crate::methods::token::token::token_transfer;
+use crate::storage::car::{read_car, read_car_available_since, read_car_balance, write_car_balance};
+use crate::storage::contract_balance::{read_contract_balance, write_contract_balance};
+use crate::storage::escrow::{read_escrow_balance, write_escrow_balance};
+use crate::storage::structs::booking::Booking;
+use crate::storage::types::car_status::CarStatus;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn has_booking_conflict(bookings: &Vec<Booking>, start_ts: u64, end_ts: u64) -> bool {
+    bookings.iter().any(|booking| booking.overlaps(start_ts, end_ts))
+}
+
+pub(crate) fn insert_booking(bookings: &mut Vec<Booking>, booking: Booking) {
+    // Keep bookings ordered by start time
+    let index = bookings
+        .iter()
+        .position(|existing| existing.start_ts > booking.start_ts)
+        .unwrap_or(bookings.len() as usize);
+
+    bookings.insert(index as u32, booking);
+}
+
+/// Moves the prepayment of a booking out of escrow and into the admin balance. The caller credits the car.
+pub(crate) fn release_booking_funds(env: &Env, booking: &Booking) -> Result<(), Error> {
+    let prepaid = booking
+        .fee_bearer
+        .renter_charge(booking.amount, booking.admin_commission)?;
+
+    let escrow_balance = read_escrow_balance(env, &booking.token)
+        .checked_sub(prepaid)
+        .ok_or(Error::OverflowError)?;
+    let contract_balance = read_contract_balance(env, &booking.token)
+        .checked_add(prepaid)
+        .ok_or(Error::OverflowError)?;
+    let admin_available = read_admin_available_to_withdraw(env, &booking.token)
+        .checked_add(booking.admin_commission)
+        .ok_or(Error::OverflowError)?;
+
+    write_escrow_balance(env, &booking.token, &escrow_balance);
+    write_contract_balance(env, &booking.token, &contract_balance);
+    write_admin_available_to_withdraw(env, &booking.token, admin_available);
+    Ok(())
+}
+
+/// Drops bookings whose window ended without a pickup. The renter forfeits the prepayment only when
+/// the car was available for the whole window, otherwise it is refunded in full.
+pub(crate) fn settle_expired_bookings(env: &Env, car_id: u32) -> Result<Vec<Booking>, Error> {
+    let now = env.ledger().timestamp();
+    let car = read_car(env, car_id)?;
+    let available_since = read_car_available_since(env, car_id);
+    let mut bookings = Vec::new(env);
+
+    for booking in read_bookings(env, car_id).iter() {
+        if booking.end_ts > now {
+            bookings.push_back(booking);
+            continue;
+        }
+
+        // Every rental and maintenance settles the bookings that ended before it, so a car that is
+        // out now has been out since before this window ended
+        if car.car_status != CarStatus::Available || available_since > booking.start_ts {
+            refund_booking(env, car_id, &booking)?;
+            continue;
+        }
+
+        release_booking_funds(env, &booking)?;
+
+        let owner_share = booking
+            .fee_bearer
+            .owner_share(booking.amount, booking.admin_commission)?;
+
+        let mut balance = read_car_balance(env, car_id, &booking.token);
+        balance.settled = balance
+            .settled
+            .checked_add(owner_share)
+            .ok_or(Error::OverflowError)?;
+        write_car_balance(env, car_id, &booking.token, &balance);
+
+        let fees_paid = booking
+            .amount
+            .checked_sub(owner_share)
+            .ok_or(Error::OverflowError)?;
+        record_earnings(env, car_id, &car.owner, &booking.token, 0, owner_share, fees_paid)?;
+
+        events::booking::booking_forfeited(env, booking.renter.clone(), car_id, booking.booking_id);
+    }
+
+    Ok(bookings)
+}
+
+/// Gives the whole prepayment back when the renter could not pick the car up.
+pub(crate) fn refund_booking(env: &Env, car_id: u32, booking: &Booking) -> Result<(), Error> {
+    let prepaid = booking
+        .fee_bearer
+        .renter_charge(booking.amount, booking.admin_commission)?;
+
+    let escrow_balance = read_escrow_balance(env, &booking.token)
+        .checked_sub(prepaid)
+        .ok_or(Error::OverflowError)?;
+    write_escrow_balance(env, &booking.token, &escrow_balance);
+
+    if prepaid > 0 {
+        token_transfer(env, &booking.token, &env.current_contract_address(), &booking.renter, &prepaid);
+    }
+
+    events::booking::booking_refunded(env, booking.renter.clone(), car_id, booking.booking_id, prepaid);
+    Ok(())
+}
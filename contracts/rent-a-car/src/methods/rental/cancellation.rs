@@ -0,0 +1,33 @@
+use soroban_sdk::Env;
+use crate::storage::cancellation_policy::read_cancellation_policy;
+use crate::storage::structs::cancellation_policy::BPS_DENOMINATOR;
+use crate::storage::types::errors::Error;
+
+/// Splits a cancellation into the refund taken from the owner share and from the admin commission.
+pub(crate) fn compute_cancellation_refund(
+    env: &Env,
+    car_id: u32,
+    start_ts: u64,
+    amount: i128,
+    admin_commission: i128,
+) -> Result<(i128, i128), Error> {
+    let policy = read_cancellation_policy(env, car_id);
+    let secs_before_start = start_ts.saturating_sub(env.ledger().timestamp());
+    let refund_bps = policy.refund_bps(secs_before_start) as i128;
+
+    let amount_refund = amount
+        .checked_mul(refund_bps)
+        .map(|scaled| scaled / BPS_DENOMINATOR as i128)
+        .ok_or(Error::OverflowError)?;
+
+    let commission_refund = if policy.refund_commission {
+        admin_commission
+            .checked_mul(refund_bps)
+            .map(|scaled| scaled / BPS_DENOMINATOR as i128)
+            .ok_or(Error::OverflowError)?
+    } else {
+        0
+    };
+
+    Ok((amount_refund, commission_refund))
+}
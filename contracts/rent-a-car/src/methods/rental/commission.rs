@@ -0,0 +1,56 @@
+use soroban_sdk::{Address, Env};
+use crate::storage::admin::read_commission_config;
+use crate::storage::commission::{read_commission_tiers, read_owner_commission};
+use crate::storage::owner_volume::read_owner_volume;
+use crate::storage::structs::cancellation_policy::BPS_DENOMINATOR;
+use crate::storage::structs::commission_config::CommissionConfig;
+use crate::storage::token::read_token;
+use crate::storage::types::{commission_mode::CommissionMode, errors::Error};
+
+/// Commission config that applies to an owner: the negotiated override, else the last volume tier reached, else the global config.
+pub(crate) fn commission_config_for(env: &Env, owner: &Address) -> CommissionConfig {
+    if let Some(config) = read_owner_commission(env, owner) {
+        return config;
+    }
+
+    let volume = read_owner_volume(env, owner);
+    let mut config = read_commission_config(env);
+
+    for tier in read_commission_tiers(env).iter() {
+        if tier.reached(&volume) {
+            config = tier.config;
+        }
+    }
+
+    config
+}
+
+/// Admin commission for a rental of the given base price in `token` under the config of the owner.
+pub(crate) fn compute_commission(env: &Env, owner: &Address, token: &Address, base_price: i128) -> Result<i128, Error> {
+    let config = commission_config_for(env, owner);
+
+    // Fixed amounts are in the reference token, any other token can only be charged a share of the price
+    if config.uses_amounts() && *token != read_token(env)? {
+        return Err(Error::CommissionTokenMismatch);
+    }
+
+    match config.mode {
+        CommissionMode::Flat => Ok(config.flat),
+        CommissionMode::Bps => {
+            let mut commission = base_price
+                .checked_mul(config.bps as i128)
+                .map(|scaled| scaled / BPS_DENOMINATOR as i128)
+                .ok_or(Error::OverflowError)?;
+
+            if let Some(min_fee) = config.min_fee {
+                commission = commission.max(min_fee);
+            }
+
+            if let Some(max_fee) = config.max_fee {
+                commission = commission.min(max_fee);
+            }
+
+            Ok(commission)
+        }
+    }
+}
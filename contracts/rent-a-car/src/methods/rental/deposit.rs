@@ -0,0 +1,109 @@
+use soroban_sdk::{Address, Env};
+
+use crate::events;
+use crate::methods::rental::statement::record_statement;
+use crate::methods::token::token::token_transfer;
+use crate::storage::car::{read_car_balance, write_car_available_since, write_car_balance};
+use crate::storage::contract_balance::{read_contract_balance, write_contract_balance};
+use crate::storage::deposit_claim::{has_deposit_claim, read_deposit_claim, remove_deposit_claim, write_deposit_claim};
+use crate::storage::escrow::{read_escrow_balance, write_escrow_balance};
+use crate::storage::structs::earnings_statement::EarningsStatement;
+use crate::storage::types::errors::Error;
+
+/// Gives back what is left of the deposit held after the last return, if any.
+pub(crate) fn release_deposit_claim(env: &Env, car_id: u32) -> Result<(), Error> {
+    if !has_deposit_claim(env, car_id) {
+        return Ok(());
+    }
+
+    let claim = read_deposit_claim(env, car_id)?;
+
+    let escrow_balance = read_escrow_balance(env, &claim.token)
+        .checked_sub(claim.amount)
+        .ok_or(Error::OverflowError)?;
+
+    write_escrow_balance(env, &claim.token, &escrow_balance);
+    remove_deposit_claim(env, car_id);
+
+    // Settling early frees the car before the window it was held for
+    let now = env.ledger().timestamp();
+    write_car_available_since(env, car_id, now.min(claim.release_ts));
+
+    if claim.amount > 0 {
+        token_transfer(env, &claim.token, &env.current_contract_address(), &claim.payer, &claim.amount);
+    }
+
+    events::deposit::deposit_refunded(env, claim.renter, car_id, claim.amount);
+    Ok(())
+}
+
+/// Gives back the deposit of the last return once its claim window has closed. The car cannot be
+/// handed over again while the owner can still withhold from that deposit.
+pub(crate) fn release_expired_deposit_claim(env: &Env, car_id: u32) -> Result<(), Error> {
+    if !has_deposit_claim(env, car_id) {
+        return Ok(());
+    }
+
+    let claim = read_deposit_claim(env, car_id)?;
+
+    if env.ledger().timestamp() < claim.release_ts {
+        return Err(Error::DepositClaimOpen);
+    }
+
+    release_deposit_claim(env, car_id)
+}
+
+/// Moves part of the deposit held after a return to the owner's settled earnings.
+pub(crate) fn withhold_from_claim(
+    env: &Env,
+    car_id: u32,
+    owner: Address,
+    amount: i128,
+    reason: u32,
+) -> Result<(), Error> {
+    let mut claim = read_deposit_claim(env, car_id)?;
+
+    if env.ledger().timestamp() >= claim.release_ts {
+        return Err(Error::DepositClaimExpired);
+    }
+
+    if amount > claim.amount {
+        return Err(Error::WithholdExceedsDeposit);
+    }
+
+    claim.amount -= amount;
+
+    let mut balance = read_car_balance(env, car_id, &claim.token);
+    balance.settled = balance
+        .settled
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+
+    let contract_balance = read_contract_balance(env, &claim.token)
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    let escrow_balance = read_escrow_balance(env, &claim.token)
+        .checked_sub(amount)
+        .ok_or(Error::OverflowError)?;
+
+    write_car_balance(env, car_id, &claim.token, &balance);
+    write_contract_balance(env, &claim.token, &contract_balance);
+    write_escrow_balance(env, &claim.token, &escrow_balance);
+
+    // Nothing is left to give back once the whole deposit is withheld
+    if claim.amount == 0 {
+        remove_deposit_claim(env, car_id);
+        write_car_available_since(env, car_id, env.ledger().timestamp());
+    } else {
+        write_deposit_claim(env, car_id, &claim);
+    }
+
+    let entry = EarningsStatement {
+        total_earned: amount,
+        ..EarningsStatement::empty()
+    };
+    record_statement(env, car_id, &owner, &claim.token, entry)?;
+
+    events::deposit::deposit_withheld(env, owner, car_id, amount, reason);
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use soroban_sdk::Env;
+use crate::storage::early_return_policy::read_early_return_policy;
+use crate::storage::structs::rental::{Rental, SECONDS_PER_DAY};
+use crate::storage::types::errors::Error;
+
+pub(crate) fn compute_early_return_refund(
+    env: &Env,
+    car_id: u32,
+    rental: &Rental,
+    returned_ts: u64,
+) -> Result<i128, Error> {
+    let policy = read_early_return_policy(env, car_id);
+
+    if !policy.enabled || returned_ts >= rental.due_ts || rental.total_days_to_rent == 0 {
+        return Ok(0);
+    }
+
+    // Only full unused days are refunded, at the rate the owner actually earned
+    let unused_days = ((rental.due_ts - returned_ts) / SECONDS_PER_DAY)
+        .min(rental.total_days_to_rent as u64) as i128;
+
+    let refund = rental
+        .fee_bearer
+        .owner_share(rental.amount, rental.admin_commission)?
+        .checked_mul(unused_days)
+        .map(|scaled| scaled / rental.total_days_to_rent as i128)
+        .ok_or(Error::OverflowError)?;
+
+    Ok(refund.saturating_sub(policy.fee).max(0))
+}
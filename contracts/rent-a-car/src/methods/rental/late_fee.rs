@@ -0,0 +1,26 @@
+use soroban_sdk::Env;
+use crate::storage::admin::read_late_grace_period;
+use crate::storage::structs::rental::Rental;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn compute_late_penalty(env: &Env, rental: &Rental, returned_ts: u64) -> Result<i128, Error> {
+    if rental.late_fee == 0 || returned_ts <= rental.due_ts {
+        return Ok(0);
+    }
+
+    let late_secs = returned_ts - rental.due_ts;
+
+    // Returns inside the grace period are not charged
+    if late_secs <= read_late_grace_period(env) {
+        return Ok(0);
+    }
+
+    // Every started hour or day counts as a full one
+    let unit_secs = rental.late_fee_unit.seconds();
+    let late_units = late_secs.div_ceil(unit_secs);
+
+    rental
+        .late_fee
+        .checked_mul(late_units as i128)
+        .ok_or(Error::OverflowError)
+}
@@ -0,0 +1,9 @@
+pub mod booking;
+pub mod cancellation;
+pub mod commission;
+pub mod deposit;
+pub mod early_return;
+pub mod late_fee;
+pub mod payer;
+pub mod settlement;
+pub mod statement;
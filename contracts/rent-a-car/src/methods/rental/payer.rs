@@ -0,0 +1,31 @@
+use soroban_sdk::{Address, Env};
+
+use crate::methods::token::token::{token_transfer, token_transfer_from};
+use crate::storage::driver_approval::has_driver_approval;
+use crate::storage::types::{errors::Error, payer::Payer};
+
+/// A third-party payer signs for the funds, or approved the renter beforehand to spend its allowance.
+pub(crate) fn check_payer(env: &Env, payer: &Payer, renter: &Address) -> Result<(), Error> {
+    match payer {
+        Payer::Auth(address) => {
+            if address != renter {
+                address.require_auth();
+            }
+        }
+        Payer::Allowance(address) => {
+            if address != renter && !has_driver_approval(env, address, renter) {
+                return Err(Error::DriverNotApproved);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Pulls the amount from the payer, out of its allowance when it pays that way.
+pub(crate) fn charge_payer(env: &Env, token: &Address, payer: &Payer, amount: &i128) {
+    match payer {
+        Payer::Auth(address) => token_transfer(env, token, address, &env.current_contract_address(), amount),
+        Payer::Allowance(address) => token_transfer_from(env, token, address, &env.current_contract_address(), amount),
+    }
+}
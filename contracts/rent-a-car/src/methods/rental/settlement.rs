@@ -0,0 +1,162 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::methods::rental::statement::record_withdrawal;
+use crate::methods::token::token::token_transfer;
+use crate::storage::car::{read_car_balance, write_car_balance};
+use crate::storage::contract_balance::{read_contract_balance, write_contract_balance};
+use crate::storage::structs::settle_cursor::SettleCursor;
+use crate::storage::types::errors::Error;
+
+/// Most cars a `settle_many` call goes through.
+pub const MAX_SETTLE_CARS: u32 = 10;
+/// Ledger entries a `settle_many` call may access, below the 100 of a transaction footprint on the network.
+pub const MAX_SETTLE_ENTRIES: u32 = 80;
+/// Ledger entries a `settle_many` call may write, below the 50 a transaction can write on the network.
+pub const MAX_SETTLE_WRITES: u32 = 40;
+/// Bytes of events a `settle_many` call may emit, below the 16 KiB a transaction can emit on the network.
+pub const MAX_SETTLE_EVENT_BYTES: u32 = 12_000;
+
+/// Cost of one step of a `settle_many` call, in the resources the network limits per transaction.
+struct SettleCost {
+    entries: u32,
+    writes: u32,
+    event_bytes: u32,
+}
+
+// Contract instance and the nonce of the caller
+const CALL_COST: SettleCost = SettleCost { entries: 3, writes: 1, event_bytes: 0 };
+// Token contract, owner liabilities, contract balance and the token balance of the contract
+const TOKEN_COST: SettleCost = SettleCost { entries: 4, writes: 3, event_bytes: 0 };
+// Payout settings
+const OWNER_COST: SettleCost = SettleCost { entries: 1, writes: 0, event_bytes: 0 };
+// Car list and the `owner_settled` event
+const PAYOUT_COST: SettleCost = SettleCost { entries: 1, writes: 0, event_bytes: 200 };
+// Owner statement, destination balance, the token transfer event and the payout in `owner_settled`
+const PAYOUT_TOKEN_COST: SettleCost = SettleCost { entries: 2, writes: 2, event_bytes: 320 };
+// Car balance, its token list, car statement and the `statement_entry` event
+const CAR_TOKEN_COST: SettleCost = SettleCost { entries: 3, writes: 3, event_bytes: 400 };
+
+/// What a `settle_many` call can still spend. Each step is charged before it runs as if every owner
+/// were paid in every allowed token and every car had a settled balance in each of them.
+pub(crate) struct SettleBudget {
+    cars: u32,
+    entries: u32,
+    writes: u32,
+    event_bytes: u32,
+    tokens: u32,
+}
+
+impl SettleBudget {
+    pub(crate) fn new(limit: u32, tokens: u32) -> Self {
+        let mut budget = SettleBudget {
+            cars: limit.min(MAX_SETTLE_CARS),
+            entries: MAX_SETTLE_ENTRIES,
+            writes: MAX_SETTLE_WRITES,
+            event_bytes: MAX_SETTLE_EVENT_BYTES,
+            tokens,
+        };
+
+        // Nothing is left to settle with when the fixed costs alone go over the budget
+        if !budget.charge(&CALL_COST, 1) || !budget.charge(&TOKEN_COST, tokens) {
+            budget.entries = 0;
+        }
+
+        budget
+    }
+
+    /// Charges looking up the payout settings of an owner.
+    pub(crate) fn charge_owner(&mut self) -> bool {
+        self.charge(&OWNER_COST, 1)
+    }
+
+    /// Charges reading the cars of an owner and paying them out in every token.
+    pub(crate) fn charge_payout(&mut self) -> bool {
+        self.charge(&PAYOUT_COST, 1) && self.charge(&PAYOUT_TOKEN_COST, self.tokens)
+    }
+
+    /// Charges settling up to `count` cars in every token, returns how many the call can afford.
+    pub(crate) fn charge_cars(&mut self, count: u32) -> u32 {
+        let mut charged = 0;
+
+        while charged < count && self.cars > 0 && self.charge(&CAR_TOKEN_COST, self.tokens) {
+            self.cars -= 1;
+            charged += 1;
+        }
+
+        charged
+    }
+
+    /// Charges `times` the cost, or nothing when the budget cannot cover all of it.
+    fn charge(&mut self, cost: &SettleCost, times: u32) -> bool {
+        let entries = cost.entries.saturating_mul(times);
+        let writes = cost.writes.saturating_mul(times);
+        let event_bytes = cost.event_bytes.saturating_mul(times);
+
+        if entries > self.entries || writes > self.writes || event_bytes > self.event_bytes {
+            return false;
+        }
+
+        self.entries -= entries;
+        self.writes -= writes;
+        self.event_bytes -= event_bytes;
+        true
+    }
+}
+
+/// Cursor to resume from. A call that cannot afford a single step would hand back the cursor it started from forever.
+pub(crate) fn next_settle_cursor(start: &SettleCursor, next: SettleCursor) -> Result<Option<SettleCursor>, Error> {
+    if next == *start {
+        return Err(Error::SettleBudgetExceeded);
+    }
+
+    Ok(Some(next))
+}
+
+/// Pays the settled balances of the given cars of the owner to `destination`, one transfer per token.
+pub(crate) fn settle_cars(
+    env: &Env,
+    owner: &Address,
+    car_ids: &Vec<u32>,
+    tokens: &Vec<Address>,
+    destination: &Address,
+) -> Result<Vec<(Address, i128)>, Error> {
+    let mut payouts = Vec::new(env);
+
+    for token in tokens.iter() {
+        let mut total: i128 = 0;
+
+        for car_id in car_ids.iter() {
+            let mut balance = read_car_balance(env, car_id, &token);
+
+            if balance.settled <= 0 {
+                continue;
+            }
+
+            total = total
+                .checked_add(balance.settled)
+                .ok_or(Error::OverflowError)?;
+            record_withdrawal(env, car_id, owner, &token, balance.settled)?;
+
+            balance.settled = 0;
+            write_car_balance(env, car_id, &token, &balance);
+        }
+
+        if total == 0 {
+            continue;
+        }
+
+        let contract_balance = read_contract_balance(env, &token)
+            .checked_sub(total)
+            .ok_or(Error::OverflowError)?;
+
+        if contract_balance < 0 {
+            return Err(Error::BalanceNotAvailableForAmountRequested);
+        }
+
+        write_contract_balance(env, &token, &contract_balance);
+        token_transfer(env, &token, &env.current_contract_address(), destination, &total);
+        payouts.push_back((token, total));
+    }
+
+    Ok(payouts)
+}
@@ -0,0 +1,57 @@
+use soroban_sdk::{Address, Env};
+
+use crate::events;
+use crate::storage::statement::{read_car_statement, read_owner_statement, write_car_statement, write_owner_statement};
+use crate::storage::structs::earnings_statement::EarningsStatement;
+use crate::storage::types::errors::Error;
+
+/// Applies a movement to the car and owner statements and publishes it, so any period can be rebuilt from events.
+pub(crate) fn record_statement(
+    env: &Env,
+    car_id: u32,
+    owner: &Address,
+    token: &Address,
+    entry: EarningsStatement,
+) -> Result<(), Error> {
+    if entry == EarningsStatement::empty() {
+        return Ok(());
+    }
+
+    let car_statement = read_car_statement(env, car_id, token).add(&entry)?;
+    let owner_statement = read_owner_statement(env, owner, token).add(&entry)?;
+
+    write_car_statement(env, car_id, token, &car_statement);
+    write_owner_statement(env, owner, token, &owner_statement);
+
+    events::statement::statement_entry(env, owner.clone(), car_id, token.clone(), entry);
+    Ok(())
+}
+
+/// Earnings credited to the owner, with the commission they bore when the owner pays the fee.
+pub(crate) fn record_earnings(
+    env: &Env,
+    car_id: u32,
+    owner: &Address,
+    token: &Address,
+    rental_count: u32,
+    earned: i128,
+    fees_paid: i128,
+) -> Result<(), Error> {
+    let entry = EarningsStatement {
+        rental_count,
+        total_earned: earned,
+        fees_paid,
+        ..EarningsStatement::empty()
+    };
+
+    record_statement(env, car_id, owner, token, entry)
+}
+
+pub(crate) fn record_withdrawal(env: &Env, car_id: u32, owner: &Address, token: &Address, amount: i128) -> Result<(), Error> {
+    let entry = EarningsStatement {
+        total_withdrawn: amount,
+        ..EarningsStatement::empty()
+    };
+
+    record_statement(env, car_id, owner, token, entry)
+}
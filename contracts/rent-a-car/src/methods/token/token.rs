@@ -3,13 +3,25 @@
     Address, Env,
 };
 
-use crate::storage::token::read_token;
+use crate::storage::token::{read_allowed_tokens, read_car_tokens};
 use crate::storage::types::errors::Error;
 
-pub fn token_transfer(env: &Env, from: &Address, to: &Address, amount: &i128) ->Result<(), Error> {
-    let token_address = read_token(env)?;
-    let token = token::TokenClient::new(env, &token_address);
+pub fn token_transfer(env: &Env, token_address: &Address, from: &Address, to: &Address, amount: &i128) {
+    let token = token::TokenClient::new(env, token_address);
     token.transfer(from, to, amount);
+}
+
+/// Pulls funds the `from` account approved for the contract beforehand.
+pub fn token_transfer_from(env: &Env, token_address: &Address, from: &Address, to: &Address, amount: &i128) {
+    let token = token::TokenClient::new(env, token_address);
+    token.transfer_from(&env.current_contract_address(), from, to, amount);
+}
+
+/// A car can be paid in a token only while the admin allows it and the owner accepts it.
+pub(crate) fn check_token_accepted(env: &Env, car_id: u32, token: &Address) -> Result<(), Error> {
+    if !read_allowed_tokens(env).contains(token) || !read_car_tokens(env, car_id)?.contains(token) {
+        return Err(Error::TokenNotAccepted);
+    }
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,10 +1,9 @@
-﻿use soroban_sdk::{symbol_short, Address, Env, Symbol};
+﻿use soroban_sdk::{Address, Env};
+use crate::storage::structs::commission_config::CommissionConfig;
+use crate::storage::structs::deposit_claim::DEFAULT_DEPOSIT_CLAIM_WINDOW;
 use crate::storage::types::errors::Error;
 use super::types::storage::DataKey;
 
-
-pub const ADMIN_KEY: &Symbol = &symbol_short!("ADMIN");
-
 pub(crate) fn has_admin(env: &Env) -> bool {
     let key = DataKey::Admin;
 
@@ -24,23 +23,23 @@ pub(crate) fn write_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&key, admin);
 }
 
-pub(crate) fn read_admin_commission(env: &Env) -> i128 {
-    let key = DataKey::AdminCommission;
+pub(crate) fn read_commission_config(env: &Env) -> CommissionConfig {
+    let key = DataKey::CommissionConfig;
     
     env.storage()
         .instance()
         .get(&key)
-        .unwrap_or(0)
+        .unwrap_or(CommissionConfig::flat(0))
 }
 
-pub(crate) fn write_admin_commission(env: &Env, commission: i128) {
-    let key = DataKey::AdminCommission;
+pub(crate) fn write_commission_config(env: &Env, config: &CommissionConfig) {
+    let key = DataKey::CommissionConfig;
     
-    env.storage().instance().set(&key, &commission);
+    env.storage().instance().set(&key, config);
 }
 
-pub(crate) fn read_admin_available_to_withdraw(env: &Env) -> i128 {
-    let key = DataKey::AdminAvailableToWithdraw;
+pub(crate) fn read_admin_available_to_withdraw(env: &Env, token: &Address) -> i128 {
+    let key = DataKey::AdminAvailableToWithdraw(token.clone());
     
     env.storage()
         .instance()
@@ -48,8 +47,57 @@ pub(crate) fn read_admin_available_to_withdraw(env: &Env) -> i128 {
         .unwrap_or(0)
 }
 
-pub(crate) fn write_admin_available_to_withdraw(env: &Env, amount: i128) {
-    let key = DataKey::AdminAvailableToWithdraw;
+pub(crate) fn write_admin_available_to_withdraw(env: &Env, token: &Address, amount: i128) {
+    let key = DataKey::AdminAvailableToWithdraw(token.clone());
     
     env.storage().instance().set(&key, &amount);
-}
\ No newline at end of file
+}
+
+pub(crate) fn read_late_grace_period(env: &Env) -> u64 {
+    let key = DataKey::LateGracePeriod;
+
+    env.storage()
+        .instance()
+        .get(&key)
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_late_grace_period(env: &Env, grace_period: u64) {
+    let key = DataKey::LateGracePeriod;
+
+    env.storage().instance().set(&key, &grace_period);
+}
+
+pub(crate) fn read_deposit_claim_window(env: &Env) -> u64 {
+    let key = DataKey::DepositClaimWindow;
+
+    env.storage()
+        .instance()
+        .get(&key)
+        .unwrap_or(DEFAULT_DEPOSIT_CLAIM_WINDOW)
+}
+
+pub(crate) fn write_deposit_claim_window(env: &Env, claim_window: u64) {
+    let key = DataKey::DepositClaimWindow;
+
+    env.storage().instance().set(&key, &claim_window);
+}
+
+/// Account besides the admin allowed to run `settle_many`, if any.
+pub(crate) fn read_settlement_keeper(env: &Env) -> Option<Address> {
+    let key = DataKey::SettlementKeeper;
+
+    env.storage().instance().get(&key)
+}
+
+pub(crate) fn write_settlement_keeper(env: &Env, keeper: &Address) {
+    let key = DataKey::SettlementKeeper;
+
+    env.storage().instance().set(&key, keeper);
+}
+
+pub(crate) fn remove_settlement_keeper(env: &Env) {
+    let key = DataKey::SettlementKeeper;
+
+    env.storage().instance().remove(&key);
+}
@@ -0,0 +1,31 @@
+use soroban_sdk::{Env, Vec};
+
+use crate::storage::types::errors::Error;
+use crate::storage::{structs::booking::Booking, types::storage::DataKey};
+
+pub(crate) fn read_bookings(env: &Env, car_id: u32) -> Vec<Booking> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Bookings(car_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_bookings(env: &Env, car_id: u32, bookings: &Vec<Booking>) {
+    let key = DataKey::Bookings(car_id);
+
+    if bookings.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, bookings);
+    }
+}
+
+pub(crate) fn next_booking_id(env: &Env) -> Result<u32, Error> {
+    let key = DataKey::NextBookingId;
+    let booking_id: u32 = env.storage().instance().get(&key).unwrap_or(0);
+
+    let next = booking_id.checked_add(1).ok_or(Error::OverflowError)?;
+    env.storage().instance().set(&key, &next);
+
+    Ok(booking_id)
+}
@@ -0,0 +1,30 @@
+use soroban_sdk::{Env, Vec};
+
+use crate::storage::{structs::cancellation_policy::CancellationPolicy, types::storage::DataKey};
+
+/// Policy of the car if the owner set one, otherwise the global policy. No refunds by default.
+pub(crate) fn read_cancellation_policy(env: &Env, car_id: u32) -> CancellationPolicy {
+    if let Some(policy) = env.storage().persistent().get(&DataKey::CarCancellationPolicy(car_id)) {
+        return policy;
+    }
+
+    env.storage()
+        .instance()
+        .get(&DataKey::CancellationPolicy)
+        .unwrap_or(CancellationPolicy {
+            tiers: Vec::new(env),
+            refund_commission: false,
+        })
+}
+
+pub(crate) fn write_cancellation_policy(env: &Env, policy: &CancellationPolicy) {
+    let key = DataKey::CancellationPolicy;
+
+    env.storage().instance().set(&key, policy);
+}
+
+pub(crate) fn write_car_cancellation_policy(env: &Env, car_id: u32, policy: &CancellationPolicy) {
+    let key = DataKey::CarCancellationPolicy(car_id);
+
+    env.storage().persistent().set(&key, policy);
+}
@@ -1,27 +1,168 @@
-﻿use soroban_sdk::{Address, Env};
+﻿use soroban_sdk::{Address, Env, Vec};
 
 use crate::storage::types::errors::Error;
-use crate::storage::{structs::car::Car, types::storage::DataKey};
+use crate::storage::{structs::{car::Car, owner_balance::OwnerBalance}, types::storage::DataKey};
 
-pub(crate) fn has_car(env: &Env, owner: &Address) -> bool {
-    env.storage().instance().has(&DataKey::Car(owner.clone()))
+pub(crate) fn has_car(env: &Env, car_id: u32) -> bool {
+    env.storage().persistent().has(&DataKey::Car(car_id))
 }
 
-pub(crate) fn read_car(env: &Env, owner: &Address) -> Result<Car, Error> {
+pub(crate) fn read_car(env: &Env, car_id: u32) -> Result<Car, Error> {
     env.storage()
-        .instance()
-        .get(&DataKey::Car(owner.clone()))
+        .persistent()
+        .get(&DataKey::Car(car_id))
         .ok_or(Error::CarNotFound)
 }
 
-pub(crate) fn write_car(env: &Env, owner: &Address, car: &Car) {
+pub(crate) fn write_car(env: &Env, car_id: u32, car: &Car) {
     env.storage()
-        .instance()
-        .set(&DataKey::Car(owner.clone()), car);
+        .persistent()
+        .set(&DataKey::Car(car_id), car);
+}
+
+/// Earnings of a car in one token, zero when the car never earned in it.
+pub(crate) fn read_car_balance(env: &Env, car_id: u32, token: &Address) -> OwnerBalance {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarBalance(car_id, token.clone()))
+        .unwrap_or(OwnerBalance { pending: 0, settled: 0 })
+}
+
+/// Keeps the owner liabilities of the token and the tokens the car holds a balance in in step
+/// with the change of this car balance.
+pub(crate) fn write_car_balance(env: &Env, car_id: u32, token: &Address, balance: &OwnerBalance) {
+    let previous = read_car_balance(env, car_id, token);
+
+    let mut tokens = read_car_balance_tokens(env, car_id);
+    let held = balance.pending != 0 || balance.settled != 0;
+
+    let changed = match (tokens.first_index_of(token), held) {
+        (None, true) => {
+            tokens.push_back(token.clone());
+            true
+        }
+        (Some(index), false) => tokens.remove(index).is_some(),
+        _ => false,
+    };
+
+    if changed {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CarBalanceTokens(car_id), &tokens);
+    }
+
+    let liabilities = read_owner_liabilities(env, token)
+        .saturating_sub(previous.pending)
+        .saturating_sub(previous.settled)
+        .saturating_add(balance.pending)
+        .saturating_add(balance.settled);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::OwnerLiabilities(token.clone()), &liabilities);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarBalance(car_id, token.clone()), balance);
+}
+
+/// Tokens in which the car has a pending or settled balance, whether or not they are still allowed.
+pub(crate) fn read_car_balance_tokens(env: &Env, car_id: u32) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarBalanceTokens(car_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Pending and settled balances of every car in one token, retired cars included.
+pub(crate) fn read_owner_liabilities(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnerLiabilities(token.clone()))
+        .unwrap_or(0)
+}
+
+/// When the car last came back from a rental or maintenance, zero when it never left.
+pub(crate) fn read_car_available_since(env: &Env, car_id: u32) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarAvailableSince(car_id))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_car_available_since(env: &Env, car_id: u32, available_since: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarAvailableSince(car_id), &available_since);
 }
 
-pub(crate) fn remove_car(env: &Env, owner: &Address) {
+pub(crate) fn next_car_id(env: &Env) -> Result<u32, Error> {
+    let key = DataKey::NextCarId;
+    let car_id: u32 = env.storage().instance().get(&key).unwrap_or(0);
+
+    let next = car_id.checked_add(1).ok_or(Error::OverflowError)?;
+    env.storage().instance().set(&key, &next);
+
+    Ok(car_id)
+}
+
+/// Number of cars ever added, IDs run from 0 up to it.
+pub(crate) fn read_car_count(env: &Env) -> u32 {
     env.storage()
         .instance()
-        .remove(&DataKey::Car(owner.clone()));
+        .get(&DataKey::NextCarId)
+        .unwrap_or(0)
+}
+
+pub(crate) fn read_owner_cars(env: &Env, owner: &Address) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnerCars(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn add_owner_car(env: &Env, owner: &Address, car_id: u32) {
+    let mut cars = read_owner_cars(env, owner);
+    cars.push_back(car_id);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::OwnerCars(owner.clone()), &cars);
+}
+
+/// The catalog index is split in chunks of consecutive car IDs so no call rewrites the whole fleet.
+pub const CAR_INDEX_CHUNK: u32 = 50;
+
+pub(crate) fn car_index_chunk(car_id: u32) -> u32 {
+    car_id / CAR_INDEX_CHUNK
+}
+
+/// Number of index chunks that can hold cars, the last one may be partly filled.
+pub(crate) fn read_car_index_chunks(env: &Env) -> u32 {
+    read_car_count(env).div_ceil(CAR_INDEX_CHUNK)
+}
+
+pub(crate) fn read_car_index(env: &Env, chunk: u32) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarIndex(chunk))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn add_car_to_index(env: &Env, car_id: u32) {
+    let chunk = car_index_chunk(car_id);
+    let mut cars = read_car_index(env, chunk);
+    cars.push_back(car_id);
+
+    env.storage().persistent().set(&DataKey::CarIndex(chunk), &cars);
+}
+
+pub(crate) fn remove_car_from_index(env: &Env, car_id: u32) {
+    let chunk = car_index_chunk(car_id);
+    let mut cars = read_car_index(env, chunk);
+
+    if let Some(index) = cars.first_index_of(car_id) {
+        cars.remove(index);
+    }
+
+    env.storage().persistent().set(&DataKey::CarIndex(chunk), &cars);
 }
@@ -0,0 +1,36 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{structs::commission_config::{CommissionConfig, CommissionTier}, types::storage::DataKey};
+
+pub(crate) fn read_owner_commission(env: &Env, owner: &Address) -> Option<CommissionConfig> {
+    let key = DataKey::OwnerCommission(owner.clone());
+
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn write_owner_commission(env: &Env, owner: &Address, config: &CommissionConfig) {
+    let key = DataKey::OwnerCommission(owner.clone());
+
+    env.storage().persistent().set(&key, config);
+}
+
+pub(crate) fn remove_owner_commission(env: &Env, owner: &Address) {
+    let key = DataKey::OwnerCommission(owner.clone());
+
+    env.storage().persistent().remove(&key);
+}
+
+pub(crate) fn read_commission_tiers(env: &Env) -> Vec<CommissionTier> {
+    let key = DataKey::CommissionTiers;
+
+    env.storage()
+        .instance()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_commission_tiers(env: &Env, tiers: &Vec<CommissionTier>) {
+    let key = DataKey::CommissionTiers;
+
+    env.storage().instance().set(&key, tiers);
+}
@@ -1,16 +1,16 @@
-﻿use soroban_sdk::Env;
+﻿use soroban_sdk::{Address, Env};
 
 use crate::storage::types::storage::DataKey;
 
-pub fn read_contract_balance(env: &Env) -> i128 {
+pub fn read_contract_balance(env: &Env, token: &Address) -> i128 {
     env.storage()
         .persistent()
-        .get(&DataKey::ContractBalance)
+        .get(&DataKey::ContractBalance(token.clone()))
         .unwrap_or(0)
 }
 
-pub fn write_contract_balance(env: &Env, amount: &i128) {
+pub fn write_contract_balance(env: &Env, token: &Address, amount: &i128) {
     env.storage()
         .persistent()
-        .set(&DataKey::ContractBalance, amount);
+        .set(&DataKey::ContractBalance(token.clone()), amount);
 }
\ No newline at end of file
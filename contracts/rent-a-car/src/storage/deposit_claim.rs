@@ -0,0 +1,29 @@
+use soroban_sdk::Env;
+
+use crate::storage::types::errors::Error;
+use crate::storage::{structs::deposit_claim::DepositClaim, types::storage::DataKey};
+
+pub(crate) fn has_deposit_claim(env: &Env, car_id: u32) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::DepositClaim(car_id))
+}
+
+pub(crate) fn read_deposit_claim(env: &Env, car_id: u32) -> Result<DepositClaim, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DepositClaim(car_id))
+        .ok_or(Error::DepositClaimNotFound)
+}
+
+pub(crate) fn write_deposit_claim(env: &Env, car_id: u32, claim: &DepositClaim) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DepositClaim(car_id), claim);
+}
+
+pub(crate) fn remove_deposit_claim(env: &Env, car_id: u32) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DepositClaim(car_id));
+}
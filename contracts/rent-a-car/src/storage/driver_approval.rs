@@ -0,0 +1,22 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+/// Whether `payer` lets `renter` rent against its token allowance.
+pub(crate) fn has_driver_approval(env: &Env, payer: &Address, renter: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::DriverApproval(payer.clone(), renter.clone()))
+}
+
+pub(crate) fn write_driver_approval(env: &Env, payer: &Address, renter: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DriverApproval(payer.clone(), renter.clone()), &true);
+}
+
+pub(crate) fn remove_driver_approval(env: &Env, payer: &Address, renter: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DriverApproval(payer.clone(), renter.clone()));
+}
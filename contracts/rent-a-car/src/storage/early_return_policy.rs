@@ -0,0 +1,19 @@
+use soroban_sdk::Env;
+
+use crate::storage::{structs::early_return_policy::EarlyReturnPolicy, types::storage::DataKey};
+
+pub(crate) fn read_early_return_policy(env: &Env, car_id: u32) -> EarlyReturnPolicy {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EarlyReturnPolicy(car_id))
+        .unwrap_or(EarlyReturnPolicy {
+            enabled: false,
+            fee: 0,
+        })
+}
+
+pub(crate) fn write_early_return_policy(env: &Env, car_id: u32, policy: &EarlyReturnPolicy) {
+    let key = DataKey::EarlyReturnPolicy(car_id);
+
+    env.storage().persistent().set(&key, policy);
+}
@@ -0,0 +1,16 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+pub fn read_escrow_balance(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EscrowBalance(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn write_escrow_balance(env: &Env, token: &Address, amount: &i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::EscrowBalance(token.clone()), amount);
+}
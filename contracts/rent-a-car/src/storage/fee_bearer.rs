@@ -0,0 +1,27 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::{fee_bearer::FeeBearer, storage::DataKey};
+
+/// Fee bearer of the owner if the admin set one, otherwise the global one. The renter pays by default.
+pub(crate) fn read_fee_bearer(env: &Env, owner: &Address) -> FeeBearer {
+    if let Some(fee_bearer) = env.storage().persistent().get(&DataKey::OwnerFeeBearer(owner.clone())) {
+        return fee_bearer;
+    }
+
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeBearer)
+        .unwrap_or(FeeBearer::Renter)
+}
+
+pub(crate) fn write_fee_bearer(env: &Env, fee_bearer: &FeeBearer) {
+    let key = DataKey::FeeBearer;
+
+    env.storage().instance().set(&key, fee_bearer);
+}
+
+pub(crate) fn write_owner_fee_bearer(env: &Env, owner: &Address, fee_bearer: &FeeBearer) {
+    let key = DataKey::OwnerFeeBearer(owner.clone());
+
+    env.storage().persistent().set(&key, fee_bearer);
+}
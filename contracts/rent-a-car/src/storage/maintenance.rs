@@ -0,0 +1,16 @@
+use soroban_sdk::{Env, Vec};
+
+use crate::storage::{structs::maintenance_record::MaintenanceRecord, types::storage::DataKey};
+
+pub(crate) fn read_maintenance_log(env: &Env, car_id: u32) -> Vec<MaintenanceRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MaintenanceLog(car_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_maintenance_log(env: &Env, car_id: u32, log: &Vec<MaintenanceRecord>) {
+    let key = DataKey::MaintenanceLog(car_id);
+
+    env.storage().persistent().set(&key, log);
+}
@@ -1,7 +1,19 @@
 ﻿pub mod admin;
+pub mod booking;
+pub mod cancellation_policy;
 pub mod car;
+pub mod commission;
 pub mod contract_balance;
+pub mod deposit_claim;
+pub mod driver_approval;
+pub mod early_return_policy;
+pub mod escrow;
+pub mod fee_bearer;
+pub mod maintenance;
+pub mod owner_volume;
+pub mod payout_settings;
 pub mod rental;
+pub mod statement;
 pub mod structs;
 pub mod token;
 pub mod types;
@@ -0,0 +1,19 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{structs::owner_volume::OwnerVolume, types::storage::DataKey};
+
+pub(crate) fn read_owner_volume(env: &Env, owner: &Address) -> OwnerVolume {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnerVolume(owner.clone()))
+        .unwrap_or(OwnerVolume {
+            completed_rentals: 0,
+            revenue: 0,
+        })
+}
+
+pub(crate) fn write_owner_volume(env: &Env, owner: &Address, volume: &OwnerVolume) {
+    let key = DataKey::OwnerVolume(owner.clone());
+
+    env.storage().persistent().set(&key, volume);
+}
@@ -0,0 +1,20 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{structs::payout_settings::PayoutSettings, types::storage::DataKey};
+
+/// Payouts go to the owner address and wait for `payout_owner` until the owner sets otherwise.
+pub(crate) fn read_payout_settings(env: &Env, owner: &Address) -> PayoutSettings {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PayoutSettings(owner.clone()))
+        .unwrap_or(PayoutSettings {
+            destination: owner.clone(),
+            auto_payout: false,
+        })
+}
+
+pub(crate) fn write_payout_settings(env: &Env, owner: &Address, settings: &PayoutSettings) {
+    let key = DataKey::PayoutSettings(owner.clone());
+
+    env.storage().persistent().set(&key, settings);
+}
@@ -1,33 +1,55 @@
-﻿use soroban_sdk::{Address, Env};
+﻿use soroban_sdk::Env;
 
 use crate::storage::types::errors::Error;
 use crate::storage::{structs::rental::Rental, types::storage::DataKey};
 
-pub(crate) fn has_rental(env: &Env, renter: &Address, car_owner: &Address) -> bool {
+pub(crate) fn has_rental(env: &Env, car_id: u32) -> bool {
     env.storage()
-        .instance()
-        .has(&DataKey::Rental(renter.clone(), car_owner.clone()))
+        .persistent()
+        .has(&DataKey::Rental(car_id))
 }
 
-pub(crate) fn write_rental(env: &Env, renter: &Address, car_owner: &Address, rental: &Rental) {
+pub(crate) fn write_rental(env: &Env, car_id: u32, rental: &Rental) {
+    if !has_rental(env, car_id) {
+        write_active_rentals(env, read_active_rentals(env) + 1);
+    }
+
     env.storage()
-        .instance()
-        .set(&DataKey::Rental(renter.clone(), car_owner.clone()), rental);
+        .persistent()
+        .set(&DataKey::Rental(car_id), rental);
 }
 
-pub(crate) fn read_rental(
-    env: &Env,
-    renter: &Address,
-    car_owner: &Address,
-) -> Result<Rental, Error> {
+pub(crate) fn read_rental(env: &Env, car_id: u32) -> Result<Rental, Error> {
     env.storage()
-        .instance()
-        .get(&DataKey::Rental(renter.clone(), car_owner.clone()))
+        .persistent()
+        .get(&DataKey::Rental(car_id))
         .ok_or(Error::RentalNotFound)
 }
 
-pub(crate) fn remove_rental(env: &Env, renter: &Address, car_owner: &Address) {
+/// Number of cars with a rental in progress, kept in step with the rental entries.
+pub(crate) fn read_active_rentals(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ActiveRentals)
+        .unwrap_or(0)
+}
+
+fn write_active_rentals(env: &Env, count: u32) {
     env.storage()
         .instance()
-        .remove(&DataKey::Rental(renter.clone(), car_owner.clone()));
+        .set(&DataKey::ActiveRentals, &count);
+}
+
+pub(crate) fn has_active_rentals(env: &Env) -> bool {
+    read_active_rentals(env) > 0
+}
+
+pub(crate) fn remove_rental(env: &Env, car_id: u32) {
+    if has_rental(env, car_id) {
+        write_active_rentals(env, read_active_rentals(env).saturating_sub(1));
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Rental(car_id));
 }
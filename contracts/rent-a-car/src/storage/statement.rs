@@ -0,0 +1,29 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{structs::earnings_statement::EarningsStatement, types::storage::DataKey};
+
+pub(crate) fn read_owner_statement(env: &Env, owner: &Address, token: &Address) -> EarningsStatement {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnerStatement(owner.clone(), token.clone()))
+        .unwrap_or(EarningsStatement::empty())
+}
+
+pub(crate) fn write_owner_statement(env: &Env, owner: &Address, token: &Address, statement: &EarningsStatement) {
+    let key = DataKey::OwnerStatement(owner.clone(), token.clone());
+
+    env.storage().persistent().set(&key, statement);
+}
+
+pub(crate) fn read_car_statement(env: &Env, car_id: u32, token: &Address) -> EarningsStatement {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarStatement(car_id, token.clone()))
+        .unwrap_or(EarningsStatement::empty())
+}
+
+pub(crate) fn write_car_statement(env: &Env, car_id: u32, token: &Address, statement: &EarningsStatement) {
+    let key = DataKey::CarStatement(car_id, token.clone());
+
+    env.storage().persistent().set(&key, statement);
+}
@@ -0,0 +1,23 @@
+use soroban_sdk::{contracttype, Address};
+
+use crate::storage::types::fee_bearer::FeeBearer;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Booking {
+    pub booking_id: u32,
+    pub renter: Address,
+    pub start_ts: u64,
+    pub end_ts: u64,
+    pub total_days: u32,
+    pub amount: i128,
+    pub admin_commission: i128,
+    pub fee_bearer: FeeBearer,
+    pub token: Address,
+}
+
+impl Booking {
+    pub(crate) fn overlaps(&self, start_ts: u64, end_ts: u64) -> bool {
+        self.start_ts < end_ts && start_ts < self.end_ts
+    }
+}
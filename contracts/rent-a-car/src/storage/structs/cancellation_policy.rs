@@ -0,0 +1,47 @@
+use soroban_sdk::{contracttype, Vec};
+
+use crate::storage::types::errors::Error;
+
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CancellationTier {
+    pub min_hours_before: u32,
+    pub refund_bps: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CancellationPolicy {
+    pub tiers: Vec<CancellationTier>,
+    pub refund_commission: bool,
+}
+
+impl CancellationPolicy {
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        if self.tiers.iter().any(|tier| tier.refund_bps > BPS_DENOMINATOR) {
+            return Err(Error::InvalidCancellationPolicy);
+        }
+
+        Ok(())
+    }
+
+    /// Refund rate of the strictest tier reached, zero when no tier applies.
+    pub(crate) fn refund_bps(&self, secs_before_start: u64) -> u32 {
+        let mut applied: Option<CancellationTier> = None;
+
+        for tier in self.tiers.iter() {
+            let reached = secs_before_start >= tier.min_hours_before as u64 * 3_600;
+            let stricter = applied
+                .as_ref()
+                .is_none_or(|current| tier.min_hours_before > current.min_hours_before);
+
+            if reached && stricter {
+                applied = Some(tier);
+            }
+        }
+
+        applied.map_or(0, |tier| tier.refund_bps)
+    }
+}
@@ -1,11 +1,17 @@
-﻿use soroban_sdk::{contracttype};
+﻿use soroban_sdk::{contracttype, Address};
 
+use crate::storage::structs::car_details::CarDetails;
 use crate::storage::types::car_status::CarStatus;
+use crate::storage::types::late_fee_unit::LateFeeUnit;
 
 #[derive(Clone)]
 #[contracttype]
 pub struct Car {
+    pub owner: Address,
+    pub details: CarDetails,
     pub price_per_day: i128,
+    pub deposit: i128,
+    pub late_fee: i128,
+    pub late_fee_unit: LateFeeUnit,
     pub car_status: CarStatus,
-    pub available_to_withdraw: i128,
 }
\ No newline at end of file
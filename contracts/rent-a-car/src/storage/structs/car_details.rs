@@ -0,0 +1,27 @@
+use soroban_sdk::{contracttype, String};
+
+use crate::storage::types::errors::Error;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CarDetails {
+    pub brand: String,
+    pub model: String,
+    pub color: String,
+    pub passengers: u32,
+    pub ac: bool,
+}
+
+impl CarDetails {
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        if self.brand.is_empty() || self.model.is_empty() || self.color.is_empty() {
+            return Err(Error::InvalidCarDetails);
+        }
+
+        if self.passengers == 0 {
+            return Err(Error::InvalidCarDetails);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+use crate::storage::structs::car::Car;
+use crate::storage::types::car_status::CarStatus;
+
+#[derive(Clone)]
+#[contracttype]
+pub struct CarFilter {
+    // Empty means any status
+    pub statuses: Vec<CarStatus>,
+    pub min_price_per_day: Option<i128>,
+    pub max_price_per_day: Option<i128>,
+    pub owner: Option<Address>,
+}
+
+impl CarFilter {
+    pub(crate) fn matches(&self, car: &Car) -> bool {
+        if !self.statuses.is_empty() && !self.statuses.contains(&car.car_status) {
+            return false;
+        }
+
+        if let Some(min_price) = self.min_price_per_day {
+            if car.price_per_day < min_price {
+                return false;
+            }
+        }
+
+        if let Some(max_price) = self.max_price_per_day {
+            if car.price_per_day > max_price {
+                return false;
+            }
+        }
+
+        if let Some(owner) = &self.owner {
+            if car.owner != *owner {
+                return false;
+            }
+        }
+
+        true
+    }
+}
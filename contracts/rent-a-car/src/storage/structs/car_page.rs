@@ -0,0 +1,17 @@
+use soroban_sdk::{contracttype, Vec};
+
+use crate::storage::structs::car::Car;
+
+#[derive(Clone)]
+#[contracttype]
+pub struct CarRecord {
+    pub car_id: u32,
+    pub car: Car,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct CarPage {
+    pub cars: Vec<CarRecord>,
+    pub next_cursor: Option<u32>,
+}
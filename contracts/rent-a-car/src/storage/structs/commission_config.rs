@@ -0,0 +1,98 @@
+use soroban_sdk::{contracttype, Vec};
+
+use crate::storage::structs::cancellation_policy::BPS_DENOMINATOR;
+use crate::storage::structs::owner_volume::OwnerVolume;
+use crate::storage::types::{commission_mode::CommissionMode, errors::Error};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CommissionConfig {
+    pub mode: CommissionMode,
+    pub flat: i128,
+    pub bps: u32,
+    pub min_fee: Option<i128>,
+    pub max_fee: Option<i128>,
+}
+
+impl CommissionConfig {
+    pub(crate) fn flat(commission: i128) -> Self {
+        CommissionConfig {
+            mode: CommissionMode::Flat,
+            flat: commission,
+            bps: 0,
+            min_fee: None,
+            max_fee: None,
+        }
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        if self.flat < 0 || self.bps > BPS_DENOMINATOR {
+            return Err(Error::InvalidCommissionConfig);
+        }
+
+        if self.min_fee.is_some_and(|min_fee| min_fee < 0) || self.max_fee.is_some_and(|max_fee| max_fee < 0) {
+            return Err(Error::InvalidCommissionConfig);
+        }
+
+        if let (Some(min_fee), Some(max_fee)) = (self.min_fee, self.max_fee) {
+            if max_fee < min_fee {
+                return Err(Error::InvalidCommissionConfig);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the config charges a fixed amount, which is denominated in the reference token.
+    pub(crate) fn uses_amounts(&self) -> bool {
+        (self.mode == CommissionMode::Flat && self.flat > 0)
+            || self.min_fee.is_some_and(|min_fee| min_fee > 0)
+            || self.max_fee.is_some()
+    }
+}
+
+/// Volume tier of the fee schedule, reached once an owner has at least `min_rentals` completed rentals or `min_revenue` revenue
+/// in the reference token. A zero threshold is not used, a tier with both at zero applies to every owner.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CommissionTier {
+    pub min_rentals: u32,
+    pub min_revenue: i128,
+    pub config: CommissionConfig,
+}
+
+impl CommissionTier {
+    pub(crate) fn reached(&self, volume: &OwnerVolume) -> bool {
+        if self.min_rentals == 0 && self.min_revenue == 0 {
+            return true;
+        }
+
+        let rentals_reached = self.min_rentals > 0 && volume.completed_rentals >= self.min_rentals;
+        let revenue_reached = self.min_revenue > 0 && volume.revenue >= self.min_revenue;
+
+        rentals_reached || revenue_reached
+    }
+}
+
+/// Tiers must be ordered by both thresholds so the last one reached is the most demanding.
+pub(crate) fn validate_commission_tiers(tiers: &Vec<CommissionTier>) -> Result<(), Error> {
+    let mut previous: Option<CommissionTier> = None;
+
+    for tier in tiers.iter() {
+        tier.config.validate()?;
+
+        if tier.min_revenue < 0 {
+            return Err(Error::InvalidCommissionConfig);
+        }
+
+        if let Some(previous) = previous {
+            if tier.min_rentals < previous.min_rentals || tier.min_revenue < previous.min_revenue {
+                return Err(Error::InvalidCommissionConfig);
+            }
+        }
+
+        previous = Some(tier);
+    }
+
+    Ok(())
+}
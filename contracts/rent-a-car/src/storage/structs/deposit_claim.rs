@@ -0,0 +1,14 @@
+use soroban_sdk::{contracttype, Address};
+
+/// A clean return gives the deposit back right away, the admin opts in to a claim window.
+pub const DEFAULT_DEPOSIT_CLAIM_WINDOW: u64 = 0;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DepositClaim {
+    pub renter: Address,
+    pub payer: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub release_ts: u64,
+}
@@ -0,0 +1,8 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct EarlyReturnPolicy {
+    pub enabled: bool,
+    pub fee: i128,
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::contracttype;
+
+use crate::storage::types::errors::Error;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct EarningsStatement {
+    pub rental_count: u32,
+    pub total_earned: i128,
+    pub total_refunded: i128,
+    pub total_withdrawn: i128,
+    pub fees_paid: i128,
+}
+
+impl EarningsStatement {
+    pub fn empty() -> Self {
+        EarningsStatement {
+            rental_count: 0,
+            total_earned: 0,
+            total_refunded: 0,
+            total_withdrawn: 0,
+            fees_paid: 0,
+        }
+    }
+
+    /// Adds the entries of another statement, used to apply a single movement.
+    pub fn add(&self, other: &EarningsStatement) -> Result<Self, Error> {
+        Ok(EarningsStatement {
+            rental_count: self.rental_count.checked_add(other.rental_count).ok_or(Error::OverflowError)?,
+            total_earned: self.total_earned.checked_add(other.total_earned).ok_or(Error::OverflowError)?,
+            total_refunded: self.total_refunded.checked_add(other.total_refunded).ok_or(Error::OverflowError)?,
+            total_withdrawn: self.total_withdrawn.checked_add(other.total_withdrawn).ok_or(Error::OverflowError)?,
+            fees_paid: self.fees_paid.checked_add(other.fees_paid).ok_or(Error::OverflowError)?,
+        })
+    }
+}
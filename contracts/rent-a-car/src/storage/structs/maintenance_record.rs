@@ -0,0 +1,9 @@
+use soroban_sdk::{contracttype, String};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct MaintenanceRecord {
+    pub reason: String,
+    pub start_ts: u64,
+    pub end_ts: u64,
+}
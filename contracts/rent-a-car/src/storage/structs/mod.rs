@@ -1,2 +1,19 @@
-﻿pub mod car;
-pub mod rental;
\ No newline at end of file
+﻿pub mod booking;
+pub mod cancellation_policy;
+pub mod car;
+pub mod car_details;
+pub mod car_filter;
+pub mod car_page;
+pub mod commission_config;
+pub mod deposit_claim;
+pub mod early_return_policy;
+pub mod earnings_statement;
+pub mod maintenance_record;
+pub mod owner_balance;
+pub mod owner_volume;
+pub mod payout_settings;
+pub mod reconciliation;
+pub mod rental;
+pub mod rental_quote;
+pub mod rental_time_status;
+pub mod settle_cursor;
\ No newline at end of file
@@ -0,0 +1,8 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct OwnerBalance {
+    pub pending: i128,
+    pub settled: i128,
+}
@@ -0,0 +1,8 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct OwnerVolume {
+    pub completed_rentals: u32,
+    pub revenue: i128,
+}
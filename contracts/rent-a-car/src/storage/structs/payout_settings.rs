@@ -0,0 +1,8 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PayoutSettings {
+    pub destination: Address,
+    pub auto_payout: bool,
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Reconciliation {
+    pub book_balance: i128,
+    pub actual_balance: i128,
+    pub owner_liabilities: i128,
+    pub admin_liabilities: i128,
+    pub escrow_liabilities: i128,
+    pub surplus: i128,
+}
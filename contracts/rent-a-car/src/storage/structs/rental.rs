@@ -1,8 +1,24 @@
-﻿use soroban_sdk::{contracttype};
+﻿use soroban_sdk::{contracttype, Address};
+
+use crate::storage::types::{fee_bearer::FeeBearer, late_fee_unit::LateFeeUnit, payer::Payer};
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
 
 #[derive(Clone)]
 #[contracttype]
 pub struct Rental {
+    pub renter: Address,
+    pub payer: Payer,
     pub total_days_to_rent: u32,
     pub amount: i128,
-}
\ No newline at end of file
+    pub admin_commission: i128,
+    pub fee_bearer: FeeBearer,
+    pub token: Address,
+    pub start_ts: u64,
+    pub due_ts: u64,
+    pub deposit: i128,
+    pub deposit_withheld: i128,
+    pub withhold_reason: u32,
+    pub late_fee: i128,
+    pub late_fee_unit: LateFeeUnit,
+}
@@ -0,0 +1,14 @@
+use soroban_sdk::contracttype;
+
+use crate::storage::types::fee_bearer::FeeBearer;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RentalQuote {
+    pub base_price: i128,
+    pub admin_commission: i128,
+    pub fee_bearer: FeeBearer,
+    pub owner_amount: i128,
+    pub deposit: i128,
+    pub total: i128,
+}
@@ -0,0 +1,11 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RentalTimeStatus {
+    pub start_ts: u64,
+    pub due_ts: u64,
+    pub remaining_secs: u64,
+    pub overdue: bool,
+    pub overdue_secs: u64,
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::contracttype;
+
+/// Position of a `settle_many` batch: the owner in the list and the first of their cars not settled yet.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SettleCursor {
+    pub owner: u32,
+    pub car: u32,
+}
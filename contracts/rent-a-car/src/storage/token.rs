@@ -1,4 +1,4 @@
-﻿use soroban_sdk::{Address, Env};
+﻿use soroban_sdk::{vec, Address, Env, Vec};
 use crate::storage::types::errors::Error;
 use crate::storage::types::storage::DataKey;
 
@@ -13,4 +13,70 @@ pub(crate) fn write_token(env: &Env, token: &Address) {
     env.storage()
         .instance()
         .set(&DataKey::Token, &token);
-}
\ No newline at end of file
+}
+
+pub(crate) fn read_allowed_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AllowedTokens)
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_allowed_tokens(env: &Env, tokens: &Vec<Address>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AllowedTokens, tokens);
+}
+
+/// Tokens the owner accepts for the car, only the contract token when none were set.
+/// Tokens replaced by `migrate_token` are read as their replacement.
+pub(crate) fn read_car_tokens(env: &Env, car_id: u32) -> Result<Vec<Address>, Error> {
+    let stored: Option<Vec<Address>> = env.storage().persistent().get(&DataKey::CarTokens(car_id));
+
+    let Some(stored) = stored else {
+        return Ok(vec![env, read_token(env)?]);
+    };
+
+    let mut tokens = Vec::new(env);
+
+    for token in stored.iter() {
+        let token = resolve_migrated_token(env, token);
+
+        if !tokens.contains(&token) {
+            tokens.push_back(token);
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Follows the migrations of a token up to the one that replaced it last.
+fn resolve_migrated_token(env: &Env, token: Address) -> Address {
+    let mut token = token;
+
+    while let Some(next) = env.storage().instance().get(&DataKey::MigratedToken(token.clone())) {
+        token = next;
+    }
+
+    token
+}
+
+/// Records that `old_token` was replaced by `new_token`, which is no longer a migrated token itself.
+pub(crate) fn write_migrated_token(env: &Env, old_token: &Address, new_token: &Address) {
+    if old_token == new_token {
+        return;
+    }
+
+    env.storage()
+        .instance()
+        .remove(&DataKey::MigratedToken(new_token.clone()));
+    env.storage()
+        .instance()
+        .set(&DataKey::MigratedToken(old_token.clone()), new_token);
+}
+
+pub(crate) fn write_car_tokens(env: &Env, car_id: u32, tokens: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarTokens(car_id), tokens);
+}
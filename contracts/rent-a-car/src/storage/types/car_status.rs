@@ -7,4 +7,5 @@ pub enum CarStatus {
     Available,
     Rented,
     Maintenance,
+    Retired,
 }
\ No newline at end of file
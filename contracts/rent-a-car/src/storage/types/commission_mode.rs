@@ -0,0 +1,9 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum CommissionMode {
+    Flat,
+    Bps,
+}
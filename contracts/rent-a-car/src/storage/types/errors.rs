@@ -3,6 +3,7 @@
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
+#[allow(clippy::enum_variant_names)]
 pub enum Error {
     ContractInitialized = 0,
     ContractNotInitialized = 1,
@@ -20,4 +21,34 @@ pub enum Error {
     TokenNotFound = 14,
     AdminNotFound = 15,
     OverflowError = 16,
+    Unauthorized = 17,
+    InvalidCarDetails = 18,
+    InvalidPageLimit = 19,
+    InsufficientPayment = 20,
+    WithholdExceedsDeposit = 21,
+    InvalidBookingRange = 22,
+    BookingConflict = 23,
+    BookingNotFound = 24,
+    BookingNotStarted = 25,
+    BookingExpired = 26,
+    InvalidCancellationPolicy = 27,
+    CancellationNotAllowed = 28,
+    RentalOverdue = 29,
+    CarInMaintenance = 30,
+    CarNotInMaintenance = 31,
+    ActiveBookingsExist = 32,
+    CarRetired = 33,
+    InvalidCommissionConfig = 34,
+    TokenNotAllowed = 35,
+    TokenNotAccepted = 36,
+    ActiveRentalsExist = 37,
+    TokenBalanceNotEmpty = 38,
+    InvalidPayoutDestination = 39,
+    NoSurplusToSweep = 40,
+    DepositClaimNotFound = 41,
+    DepositClaimExpired = 42,
+    DriverNotApproved = 43,
+    DepositClaimOpen = 44,
+    CommissionTokenMismatch = 45,
+    SettleBudgetExceeded = 46,
 }
\ No newline at end of file
@@ -0,0 +1,29 @@
+use soroban_sdk::contracttype;
+
+use crate::storage::types::errors::Error;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum FeeBearer {
+    Renter,
+    Owner,
+}
+
+impl FeeBearer {
+    /// What the renter pays for a rental amount and its commission.
+    pub fn renter_charge(&self, amount: i128, admin_commission: i128) -> Result<i128, Error> {
+        match self {
+            FeeBearer::Renter => amount.checked_add(admin_commission).ok_or(Error::OverflowError),
+            FeeBearer::Owner => Ok(amount),
+        }
+    }
+
+    /// What the owner keeps of a rental amount once the commission is paid.
+    pub fn owner_share(&self, amount: i128, admin_commission: i128) -> Result<i128, Error> {
+        match self {
+            FeeBearer::Renter => Ok(amount),
+            FeeBearer::Owner => amount.checked_sub(admin_commission).ok_or(Error::OverflowError),
+        }
+    }
+}
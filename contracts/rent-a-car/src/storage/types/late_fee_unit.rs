@@ -0,0 +1,18 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum LateFeeUnit {
+    Hour,
+    Day,
+}
+
+impl LateFeeUnit {
+    pub fn seconds(&self) -> u64 {
+        match self {
+            LateFeeUnit::Hour => 3_600,
+            LateFeeUnit::Day => 86_400,
+        }
+    }
+}
@@ -1,3 +1,7 @@
 ﻿pub mod car_status;
 pub mod storage;
-pub mod errors;
\ No newline at end of file
+pub mod errors;
+pub mod late_fee_unit;
+pub mod commission_mode;
+pub mod fee_bearer;
+pub mod payer;
\ No newline at end of file
@@ -0,0 +1,17 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub enum Payer {
+    Auth(Address),
+    Allowance(Address),
+}
+
+impl Payer {
+    /// Account the rental is charged to and refunded to.
+    pub fn address(&self) -> Address {
+        match self {
+            Payer::Auth(address) | Payer::Allowance(address) => address.clone(),
+        }
+    }
+}
@@ -5,9 +5,40 @@
 pub enum DataKey {
     Admin,
     Token,
-    ContractBalance,
-    AdminCommission,
-    AdminAvailableToWithdraw,
-    Car(Address),
-    Rental(Address, Address),
+    AllowedTokens,
+    CarTokens(u32),
+    MigratedToken(Address),
+    ContractBalance(Address),
+    EscrowBalance(Address),
+    CommissionConfig,
+    OwnerCommission(Address),
+    CommissionTiers,
+    OwnerVolume(Address),
+    OwnerStatement(Address, Address),
+    CarStatement(u32, Address),
+    PayoutSettings(Address),
+    DriverApproval(Address, Address),
+    FeeBearer,
+    OwnerFeeBearer(Address),
+    AdminAvailableToWithdraw(Address),
+    LateGracePeriod,
+    DepositClaimWindow,
+    SettlementKeeper,
+    NextCarId,
+    Car(u32),
+    CarBalance(u32, Address),
+    CarBalanceTokens(u32),
+    OwnerLiabilities(Address),
+    CarAvailableSince(u32),
+    OwnerCars(Address),
+    CarIndex(u32),
+    Rental(u32),
+    ActiveRentals,
+    DepositClaim(u32),
+    NextBookingId,
+    Bookings(u32),
+    CancellationPolicy,
+    CarCancellationPolicy(u32),
+    EarlyReturnPolicy(u32),
+    MaintenanceLog(u32),
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
-﻿use crate::tests::config::utils::get_contract_events;
+﻿use crate::tests::config::utils::{create_car_details, get_contract_events};
 use crate::{storage::{car::read_car, types::car_status::CarStatus}, tests::config::contract::ContractTest};
-use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, String, Symbol};
 use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
 
 #[test]
@@ -10,24 +10,28 @@ pub fn test_add_car_successfully() {
 
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
+    let details = create_car_details(&env);
 
-    contract
+    let car_id = contract
         .mock_auths(&[MockAuth {
             address: &admin,
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "add_car",
-                args: (owner.clone(), price_per_day).into_val(&env),
+                args: (owner.clone(), price_per_day, details.clone()).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .add_car( &owner, &price_per_day);
+        .add_car( &owner, &price_per_day, &details);
     let contract_events = get_contract_events(&env, &contract.address);
 
     let stored_car = env.as_contract(&contract.address, || {
-        read_car(&env, &owner)
+        read_car(&env, car_id)
     }).unwrap();
 
+    assert_eq!(car_id, 0);
+    assert_eq!(stored_car.owner, owner);
+    assert_eq!(stored_car.details, details);
     assert_eq!(stored_car.price_per_day, price_per_day);
     assert_eq!(stored_car.car_status, CarStatus::Available);
 
@@ -41,6 +45,7 @@ pub fn test_add_car_successfully() {
                     &env,
                     *Symbol::new(&env, "car_added").as_val(),
                     owner.clone().into_val(&env),
+                    car_id.into_val(&env),
                 ],
                 price_per_day.into_val(&env)
             )
@@ -57,7 +62,7 @@ pub fn test_add_car_with_zero_price_fails() {
 
     env.mock_all_auths();
 
-    contract.add_car(&owner, &price_per_day);
+    contract.add_car(&owner, &price_per_day, &create_car_details(&env));
 }
 
 #[test]
@@ -69,18 +74,51 @@ pub fn test_add_car_with_negative_price_fails() {
 
     env.mock_all_auths();
 
-    contract.add_car(&owner, &price_per_day);
+    contract.add_car(&owner, &price_per_day, &create_car_details(&env));
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #10)")]
-pub fn test_add_car_already_exists_fails() {
+pub fn test_add_multiple_cars_for_same_owner() {
     let ContractTest { contract, env, .. } = ContractTest::setup();
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
 
     env.mock_all_auths();
 
-    contract.add_car(&owner, &price_per_day);
-    contract.add_car(&owner, &price_per_day);
-}
\ No newline at end of file
+    let first_car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    let second_car_id = contract.add_car(&owner, &(price_per_day * 2), &create_car_details(&env));
+
+    assert_ne!(first_car_id, second_car_id);
+    assert_eq!(contract.get_owner_cars(&owner), vec![&env, first_car_id, second_car_id]);
+
+    let second_car = env.as_contract(&contract.address, || read_car(&env, second_car_id)).unwrap();
+    assert_eq!(second_car.owner, owner);
+    assert_eq!(second_car.price_per_day, price_per_day * 2);
+}
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+pub fn test_add_car_with_zero_passengers_fails() {
+    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let mut details = create_car_details(&env);
+    details.passengers = 0;
+
+    env.mock_all_auths();
+
+    contract.add_car(&owner, &price_per_day, &details);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+pub fn test_add_car_with_empty_brand_fails() {
+    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let mut details = create_car_details(&env);
+    details.brand = String::from_str(&env, "");
+
+    env.mock_all_auths();
+
+    contract.add_car(&owner, &price_per_day, &details);
+}
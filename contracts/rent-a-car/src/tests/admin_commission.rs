@@ -1,6 +1,6 @@
 use soroban_sdk::{testutils::Address as _, Address, IntoVal};
 use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
-use crate::{storage::admin::read_admin_commission, tests::config::contract::ContractTest};
+use crate::{storage::admin::read_commission_config, tests::config::contract::ContractTest};
 
 #[test]
 pub fn test_set_admin_commission_successfully() {
@@ -21,7 +21,7 @@ pub fn test_set_admin_commission_successfully() {
         .set_admin_commission(&commission);
 
     let stored_commission = env.as_contract(&contract.address, || {
-        read_admin_commission(&env)
+        read_commission_config(&env).flat
     });
 
     assert_eq!(stored_commission, commission);
@@ -46,7 +46,7 @@ pub fn test_set_admin_commission_zero() {
         .set_admin_commission(&commission);
 
     let stored_commission = env.as_contract(&contract.address, || {
-        read_admin_commission(&env)
+        read_commission_config(&env).flat
     });
 
     assert_eq!(stored_commission, commission);
@@ -96,14 +96,14 @@ pub fn test_update_admin_commission() {
     contract.set_admin_commission(&first_commission);
 
     let stored_commission = env.as_contract(&contract.address, || {
-        read_admin_commission(&env)
+        read_commission_config(&env).flat
     });
     assert_eq!(stored_commission, first_commission);
 
     contract.set_admin_commission(&second_commission);
 
     let stored_commission = env.as_contract(&contract.address, || {
-        read_admin_commission(&env)
+        read_commission_config(&env).flat
     });
     assert_eq!(stored_commission, second_commission);
 }
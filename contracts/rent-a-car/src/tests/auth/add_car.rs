@@ -1,5 +1,5 @@
 ﻿use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
-use crate::tests::config::contract::ContractTest;
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
 
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
@@ -9,6 +9,7 @@ pub fn test_unauthorized_user_cannot_add_car() {
     let fake_admin = Address::generate(&env);
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
+    let details = create_car_details(&env);
 
     contract
         .mock_auths(&[MockAuth {
@@ -16,8 +17,8 @@ pub fn test_unauthorized_user_cannot_add_car() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "add_car",
-                args: (owner.clone(), price_per_day).into_val(&env),
+                args: (owner.clone(), price_per_day, details.clone()).into_val(&env),
                 sub_invokes: &[],
             },
-        }]).add_car(&owner, &price_per_day);
+        }]).add_car(&owner, &price_per_day, &details);
 }
\ No newline at end of file
@@ -0,0 +1,25 @@
+use soroban_sdk::IntoVal;
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address};
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_cancel_reservation() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_renter = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let car_id = 0_u32;
+    let booking_id = 0_u32;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "cancel_reservation",
+                args: (renter.clone(), car_id, booking_id).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).cancel_reservation(&renter, &car_id, &booking_id);
+}
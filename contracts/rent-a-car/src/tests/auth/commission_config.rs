@@ -0,0 +1,30 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::storage::structs::commission_config::CommissionConfig;
+use crate::storage::types::commission_mode::CommissionMode;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_commission_config() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let config = CommissionConfig {
+        mode: CommissionMode::Bps,
+        flat: 0,
+        bps: 500,
+        min_fee: None,
+        max_fee: None,
+    };
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_commission_config",
+                args: (config.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_commission_config(&config);
+}
@@ -0,0 +1,56 @@
+use soroban_sdk::IntoVal;
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address};
+use crate::storage::types::payer::Payer;
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_extend_rental() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_renter = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let car_id = 0_u32;
+    let extra_days = 1_u32;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "extend_rental",
+                args: (renter.clone(), car_id, extra_days).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).extend_rental(&renter, &car_id, &extra_days);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_payer_must_sign_extend_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+    let extra_days = 1_u32;
+
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&employer, &10_000);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental_paid_by(&renter, &car_id, &1, &1500, &token_client.address, &Payer::Auth(employer));
+
+    // Only the renter signs, the employer never approves the extra days
+    contract
+        .mock_auths(&[MockAuth {
+            address: &renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "extend_rental",
+                args: (renter.clone(), car_id, extra_days).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).extend_rental(&renter, &car_id, &extra_days);
+}
@@ -0,0 +1,41 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::storage::types::fee_bearer::FeeBearer;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_fee_bearer() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_fee_bearer",
+                args: (FeeBearer::Owner,).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_fee_bearer(&FeeBearer::Owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_owner_cannot_set_own_fee_bearer() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_owner_fee_bearer",
+                args: (owner.clone(), FeeBearer::Renter).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_owner_fee_bearer(&owner, &FeeBearer::Renter);
+}
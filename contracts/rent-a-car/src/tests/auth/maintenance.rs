@@ -0,0 +1,45 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address, String};
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_start_maintenance() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_caller = Address::generate(&env);
+    let caller = Address::generate(&env);
+    let car_id = 0_u32;
+    let reason = String::from_str(&env, "Oil change");
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_caller,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "start_maintenance",
+                args: (caller.clone(), car_id, reason.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).start_maintenance(&caller, &car_id, &reason);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_end_maintenance() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_caller = Address::generate(&env);
+    let caller = Address::generate(&env);
+    let car_id = 0_u32;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_caller,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "end_maintenance",
+                args: (caller.clone(), car_id).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).end_maintenance(&caller, &car_id);
+}
@@ -0,0 +1,22 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_migrate_token() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let new_token = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "migrate_token",
+                args: (new_token.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).migrate_token(&new_token);
+}
@@ -4,4 +4,27 @@ mod remove_car;
 mod rental;
 mod admin_commission;
 mod withdraw_admin_commission;
-mod return_car;
\ No newline at end of file
+mod return_car;
+mod update_car_details;
+mod set_car_deposit;
+mod withhold_deposit;
+mod release_deposit;
+mod set_deposit_claim_window;
+mod set_car_late_fee;
+mod set_late_grace_period;
+mod reserve;
+mod pickup;
+mod set_cancellation_policy;
+mod cancel_reservation;
+mod extend_rental;
+mod set_car_early_return_policy;
+mod maintenance;
+mod commission_config;
+mod fee_bearer;
+mod owner_commission;
+mod token;
+mod migrate_token;
+mod rental_paid_by;
+mod payout_settings;
+mod sweep_surplus;
+mod settle_many;
@@ -0,0 +1,66 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, vec, IntoVal, Address};
+use crate::storage::structs::commission_config::{CommissionConfig, CommissionTier};
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_owner_cannot_set_own_commission() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let config = CommissionConfig::flat(0);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_owner_commission",
+                args: (owner.clone(), config.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_owner_commission(&owner, &config);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_remove_owner_commission() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "remove_owner_commission",
+                args: (owner.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).remove_owner_commission(&owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_commission_tiers() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let tiers = vec![
+        &env,
+        CommissionTier { min_rentals: 1, min_revenue: 0, config: CommissionConfig::flat(0) },
+    ];
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_commission_tiers",
+                args: (tiers.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_commission_tiers(&tiers);
+}
@@ -1,24 +1,31 @@
-﻿use soroban_sdk::IntoVal;
+﻿use crate::tests::config::utils::create_car_details;
+use soroban_sdk::IntoVal;
 use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address};
 use crate::tests::config::contract::ContractTest;
 
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
 pub fn test_unauthorized_user_cannot_payout_owner() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let (token_client, _, _) = token;
 
     let owner = Address::generate(&env);
     let fake_owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
     let amount = 4500_i128;
 
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+
     contract
         .mock_auths(&[MockAuth {
             address: &fake_owner,
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "payout_owner",
-                args: (owner.clone(), amount).into_val(&env),
+                args: (car_id, token_client.address.clone(), amount).into_val(&env),
                 sub_invokes: &[],
             },
-        }]).payout_owner(&owner, &amount);
+        }]).payout_owner(&car_id, &token_client.address, &amount);
 }
\ No newline at end of file
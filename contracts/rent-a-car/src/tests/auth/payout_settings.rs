@@ -0,0 +1,22 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_payout_settings() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let attacker = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_payout_settings",
+                args: (owner.clone(), attacker.clone(), true).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_payout_settings(&owner, &attacker, &true);
+}
@@ -0,0 +1,32 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_release_deposit_before_claim_window() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &500);
+    contract.set_deposit_claim_window(&86_400);
+    contract.rental(&renter, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+    contract.return_car(&renter, &car_id);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "release_deposit",
+                args: (car_id,).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).release_deposit(&car_id);
+}
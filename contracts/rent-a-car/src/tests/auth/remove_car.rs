@@ -7,7 +7,7 @@ pub fn test_unauthorized_user_cannot_remove_car() {
     let ContractTest { env, contract, .. } = ContractTest::setup();
 
     let fake_admin = Address::generate(&env);
-    let owner = Address::generate(&env);
+    let car_id = 0_u32;
 
     contract
         .mock_auths(&[MockAuth {
@@ -15,8 +15,8 @@ pub fn test_unauthorized_user_cannot_remove_car() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "remove_car",
-                args: (owner.clone(),).into_val(&env),
+                args: (car_id,).into_val(&env),
                 sub_invokes: &[],
             },
-        }]).remove_car(&owner);
+        }]).remove_car(&car_id);
 }
\ No newline at end of file
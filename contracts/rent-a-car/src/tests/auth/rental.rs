@@ -5,11 +5,12 @@ use crate::tests::config::contract::ContractTest;
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
 pub fn test_unauthorized_user_cannot_rent_car() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let (token_client, _, _) = token;
 
     let fake_renter = Address::generate(&env);
     let renter = Address::generate(&env);
-    let owner = Address::generate(&env);
+    let car_id = 0_u32;
     let total_days = 3_u32;
     let amount = 4500_i128;
 
@@ -19,8 +20,8 @@ pub fn test_unauthorized_user_cannot_rent_car() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "rental",
-                args: (renter.clone(), owner.clone(), total_days, amount).into_val(&env),
+                args: (renter.clone(), car_id, total_days, amount, token_client.address.clone()).into_val(&env),
                 sub_invokes: &[],
             },
-        }]).rental(&renter, &owner, &total_days, &amount);
+        }]).rental(&renter, &car_id, &total_days, &amount, &token_client.address);
 }
\ No newline at end of file
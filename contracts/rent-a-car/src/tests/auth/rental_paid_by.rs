@@ -0,0 +1,88 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::storage::types::payer::Payer;
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_payer_must_sign_rental_paid_by() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+    let amount = 1500_i128;
+
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&employer, &10_000);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    let payer = Payer::Auth(employer);
+
+    // Only the renter signs, the employer never approves the payment
+    contract
+        .mock_auths(&[MockAuth {
+            address: &renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "rental_paid_by",
+                args: (renter.clone(), car_id, 1_u32, amount, token_client.address.clone(), payer.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).rental_paid_by(&renter, &car_id, &1, &amount, &token_client.address, &payer);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #43)")]
+pub fn test_unrelated_renter_cannot_spend_payer_allowance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let driver = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let employer = Address::generate(&env);
+    let amount = 1500_i128;
+
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&employer, &10_000);
+    token_client.approve(&employer, &contract.address, &5000, &(env.ledger().sequence() + 100));
+    contract.set_driver_approval(&employer, &driver, &true);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    let payer = Payer::Allowance(employer);
+
+    // The stranger signs for themselves but was never approved by the employer
+    contract
+        .mock_auths(&[MockAuth {
+            address: &stranger,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "rental_paid_by",
+                args: (stranger.clone(), car_id, 1_u32, amount, token_client.address.clone(), payer.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).rental_paid_by(&stranger, &car_id, &1, &amount, &token_client.address, &payer);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_renter_cannot_approve_themselves_as_driver() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_driver_approval",
+                args: (employer.clone(), renter.clone(), true).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_driver_approval(&employer, &renter, &true);
+}
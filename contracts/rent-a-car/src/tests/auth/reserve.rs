@@ -0,0 +1,27 @@
+use soroban_sdk::IntoVal;
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, Address};
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_reserve_car() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let (token_client, _, _) = token;
+
+    let fake_renter = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let car_id = 0_u32;
+    let start_ts = 86_400_u64;
+    let end_ts = 2 * 86_400_u64;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "reserve",
+                args: (renter.clone(), car_id, start_ts, end_ts, token_client.address.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).reserve(&renter, &car_id, &start_ts, &end_ts, &token_client.address);
+}
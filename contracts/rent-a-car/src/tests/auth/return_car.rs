@@ -1,3 +1,4 @@
+use crate::tests::config::utils::create_car_details;
 use soroban_sdk::{testutils::Address as _, Address, IntoVal};
 use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
 use crate::tests::config::contract::ContractTest;
@@ -16,13 +17,13 @@ pub fn test_unauthorized_user_cannot_return_car() {
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
 
     // Unauthorized user tries to return the car (will fail because no rental exists for unauthorized)
     contract
@@ -31,10 +32,10 @@ pub fn test_unauthorized_user_cannot_return_car() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "return_car",
-                args: (unauthorized.clone(), owner.clone()).into_val(&env),
+                args: (unauthorized.clone(), car_id).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .return_car(&unauthorized, &owner);
+        .return_car(&unauthorized, &car_id);
 }
 
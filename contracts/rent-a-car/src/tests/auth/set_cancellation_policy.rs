@@ -0,0 +1,54 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, vec, IntoVal, Address};
+use crate::storage::structs::cancellation_policy::{CancellationPolicy, CancellationTier};
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_cancellation_policy() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let policy = CancellationPolicy {
+        tiers: vec![&env, CancellationTier { min_hours_before: 24, refund_bps: 10_000 }],
+        refund_commission: true,
+    };
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_cancellation_policy",
+                args: (policy.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_cancellation_policy(&policy);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_car_cancellation_policy() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+    let policy = CancellationPolicy {
+        tiers: vec![&env, CancellationTier { min_hours_before: 24, refund_bps: 10_000 }],
+        refund_commission: true,
+    };
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_car_cancellation_policy",
+                args: (car_id, policy.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_car_cancellation_policy(&car_id, &policy);
+}
@@ -0,0 +1,27 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_car_deposit() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+    let deposit = 1000_i128;
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_car_deposit",
+                args: (car_id, deposit).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_car_deposit(&car_id, &deposit);
+}
@@ -0,0 +1,28 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::storage::structs::early_return_policy::EarlyReturnPolicy;
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_car_early_return_policy() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+    let policy = EarlyReturnPolicy { enabled: true, fee: 200 };
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_car_early_return_policy",
+                args: (car_id, policy.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_car_early_return_policy(&car_id, &policy);
+}
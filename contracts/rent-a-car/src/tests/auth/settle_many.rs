@@ -0,0 +1,45 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, vec, IntoVal, Address};
+use crate::storage::structs::settle_cursor::SettleCursor;
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_settlement_keeper() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let keeper = Some(Address::generate(&env));
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_settlement_keeper",
+                args: (keeper.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_settlement_keeper(&keeper);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_settle_many_as_admin_without_its_signature_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let owners = vec![&env, Address::generate(&env)];
+    let cursor = SettleCursor { owner: 0, car: 0 };
+    let limit = 10_u32;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "settle_many",
+                args: (admin.clone(), owners.clone(), cursor.clone(), limit).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).settle_many(&admin, &owners, &cursor, &limit);
+}
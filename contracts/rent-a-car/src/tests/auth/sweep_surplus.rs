@@ -0,0 +1,25 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_sweep_surplus() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&contract.address, &1000);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "sweep_surplus",
+                args: (token_client.address.clone(), fake_admin.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).sweep_surplus(&token_client.address, &fake_admin);
+}
@@ -0,0 +1,48 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, vec, Address, IntoVal};
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_allow_token() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let new_token = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "allow_token",
+                args: (new_token.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).allow_token(&new_token);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_car_tokens() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+    let (token_client, _, _) = token;
+
+    env.mock_all_auths();
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    let tokens = vec![&env, token_client.address.clone()];
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_car_tokens",
+                args: (car_id, tokens.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).set_car_tokens(&car_id, &tokens);
+}
@@ -0,0 +1,28 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_update_car_details() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let details = create_car_details(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &price_per_day, &details);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "update_car_details",
+                args: (owner.clone(), car_id, details.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).update_car_details(&owner, &car_id, &details);
+}
@@ -5,7 +5,8 @@ use crate::tests::config::contract::ContractTest;
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
 pub fn test_unauthorized_user_cannot_withdraw_admin_commission() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let (token_client, _, _) = token;
 
     let fake_admin = Address::generate(&env);
     let amount = 100_i128;
@@ -16,10 +17,10 @@ pub fn test_unauthorized_user_cannot_withdraw_admin_commission() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "withdraw_admin_commission",
-                args: (amount,).into_val(&env),
+                args: (token_client.address.clone(), amount).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .withdraw_admin_commission(&amount);
+        .withdraw_admin_commission(&token_client.address, &amount);
 }
 
@@ -0,0 +1,34 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_withhold_deposit() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let withheld = 500_i128;
+    let reason = 1_u32;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &1000);
+    contract.rental(&renter, &car_id, &3, &contract.get_quote(&car_id, &3).total, &token_client.address);
+
+    // The renter cannot keep part of their own deposit on the owner's behalf
+    contract
+        .mock_auths(&[MockAuth {
+            address: &renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "withhold_deposit",
+                args: (car_id, withheld, reason).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).withhold_deposit(&car_id, &withheld, &reason);
+}
@@ -0,0 +1,283 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::storage::{contract_balance::read_contract_balance, escrow::read_escrow_balance};
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, create_policy, get_contract_events}};
+use crate::storage::structs::earnings_statement::EarningsStatement;
+
+const DAY: u64 = 86_400;
+const HOUR: u64 = 3_600;
+
+#[test]
+pub fn test_cancel_reservation_full_refund_well_ahead() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission = 100_i128;
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+    let start_ts = now + 3 * DAY;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    contract.set_admin_commission(&commission);
+    contract.set_cancellation_policy(&create_policy(&env, true));
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &start_ts, &(start_ts + 2 * DAY), &token_client.address);
+
+    contract.cancel_reservation(&renter, &car_id, &booking_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let refund = 3000 + commission;
+    assert_eq!(token_client.balance(&renter), amount_mint);
+    assert_eq!(contract.get_bookings(&car_id, &now, &(now + 10 * DAY)).len(), 0);
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, 0);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, 0);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "statement_entry").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (
+                    token_client.address.clone(),
+                    EarningsStatement { rental_count: 0, total_earned: 3000, total_refunded: 3000, total_withdrawn: 0, fees_paid: 0 },
+                ).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "reservation_cancelled").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (booking_id, refund).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_cancel_reservation_partial_refund_keeps_commission() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission = 100_i128;
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+    let start_ts = now + 30 * HOUR;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    contract.set_admin_commission(&commission);
+    contract.set_cancellation_policy(&create_policy(&env, false));
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &start_ts, &(start_ts + 2 * DAY), &token_client.address);
+
+    contract.cancel_reservation(&renter, &car_id, &booking_id);
+
+    // 50% of the rental amount, the commission is not refunded
+    let refund = 1500_i128;
+    assert_eq!(token_client.balance(&renter), amount_mint - 3000 - commission + refund);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, 1500);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), commission);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, 1500 + commission);
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, 0);
+}
+
+#[test]
+pub fn test_cancel_reservation_refund_drops_as_start_approaches() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+    let start_ts = now + 4 * DAY;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    contract.set_cancellation_policy(&create_policy(&env, true));
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &start_ts, &(start_ts + 2 * DAY), &token_client.address);
+
+    // Booked four days ahead, cancelled once only 30 hours are left
+    env.ledger().set_timestamp(start_ts - 30 * HOUR);
+    contract.cancel_reservation(&renter, &car_id, &booking_id);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - 1500);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, 1500);
+}
+
+#[test]
+pub fn test_cancel_reservation_late_gets_no_refund() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+    let start_ts = now + 12 * HOUR;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    contract.set_cancellation_policy(&create_policy(&env, true));
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &start_ts, &(start_ts + DAY), &token_client.address);
+
+    contract.cancel_reservation(&renter, &car_id, &booking_id);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - 1500);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, 1500);
+}
+
+#[test]
+pub fn test_cancel_reservation_while_car_is_still_rented_refunds_in_full() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter_1 = Address::generate(&env);
+    let renter_2 = Address::generate(&env);
+    let commission = 100_i128;
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter_1, &amount_mint);
+    token_admin.mint(&renter_2, &amount_mint);
+
+    contract.set_admin_commission(&commission);
+    contract.set_cancellation_policy(&create_policy(&env, true));
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter_1, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+    let booking_id = contract.reserve(&renter_2, &car_id, &(now + DAY), &(now + 2 * DAY), &token_client.address);
+
+    // The walk-in renter is late, so the booking can not be picked up
+    env.ledger().set_timestamp(now + DAY + HOUR);
+    contract.cancel_reservation(&renter_2, &car_id, &booking_id);
+
+    assert_eq!(token_client.balance(&renter_2), amount_mint);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), commission);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, 0);
+}
+
+#[test]
+pub fn test_cancel_reservation_while_car_is_held_for_deposit_claim_refunds_in_full() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter_1 = Address::generate(&env);
+    let renter_2 = Address::generate(&env);
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter_1, &amount_mint);
+    token_admin.mint(&renter_2, &amount_mint);
+
+    contract.set_cancellation_policy(&create_policy(&env, true));
+    contract.set_deposit_claim_window(&(2 * DAY));
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &500);
+    contract.rental(&renter_1, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+    let booking_id = contract.reserve(&renter_2, &car_id, &(now + 2 * DAY), &(now + 3 * DAY), &token_client.address);
+
+    // The car is back on time but the owner still has until the end of the claim window
+    env.ledger().set_timestamp(now + DAY);
+    contract.return_car(&renter_1, &car_id);
+
+    env.ledger().set_timestamp(now + 2 * DAY + HOUR);
+    contract.cancel_reservation(&renter_2, &car_id, &booking_id);
+
+    assert_eq!(token_client.balance(&renter_2), amount_mint);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+pub fn test_cancel_reservation_by_other_renter_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let other_renter = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &(now + DAY), &(now + 2 * DAY), &token_client.address);
+
+    contract.cancel_reservation(&other_renter, &car_id, &booking_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+pub fn test_cancel_expired_reservation_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &(now + DAY), &(now + 2 * DAY), &token_client.address);
+
+    env.ledger().set_timestamp(now + 2 * DAY);
+    contract.cancel_reservation(&renter, &car_id, &booking_id);
+}
@@ -0,0 +1,119 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
+use crate::storage::structs::commission_config::CommissionConfig;
+use crate::storage::types::commission_mode::CommissionMode;
+use crate::tests::config::{contract::ContractTest, utils::{bps_config, create_car_details, get_contract_events}};
+
+#[test]
+pub fn test_set_commission_config_successfully() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let config = bps_config(500, Some(10), Some(1_000));
+
+    env.mock_all_auths();
+
+    contract.set_commission_config(&config);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_commission_config(), config);
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![&env, *Symbol::new(&env, "commission_config_set").as_val()],
+                config.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_default_commission_config_is_flat_zero() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    let config = contract.get_commission_config();
+
+    assert_eq!(config.mode, CommissionMode::Flat);
+    assert_eq!(config.flat, 0);
+}
+
+#[test]
+pub fn test_set_admin_commission_switches_back_to_flat() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.set_commission_config(&bps_config(500, None, None));
+    contract.set_admin_commission(&100);
+
+    assert_eq!(contract.get_commission_config(), CommissionConfig {
+        mode: CommissionMode::Flat,
+        flat: 100,
+        bps: 0,
+        min_fee: None,
+        max_fee: None,
+    });
+}
+
+#[test]
+pub fn test_bps_commission_scales_with_rental_amount() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount_mint = 100_000_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    // 5% of the rental amount
+    contract.set_commission_config(&bps_config(500, None, None));
+    let car_id = contract.add_car(&owner, &1000_i128, &create_car_details(&env));
+
+    assert_eq!(contract.get_quote(&car_id, &1).admin_commission, 50);
+    assert_eq!(contract.get_quote(&car_id, &30).admin_commission, 1_500);
+
+    contract.rental(&renter, &car_id, &30, &contract.get_quote(&car_id, &30).total, &token_client.address);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - 30_000 - 1_500);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), 1_500);
+}
+
+#[test]
+pub fn test_bps_commission_is_clamped_to_min_and_max() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.set_commission_config(&bps_config(500, Some(100), Some(1_000)));
+    let car_id = contract.add_car(&owner, &1000_i128, &create_car_details(&env));
+
+    assert_eq!(contract.get_quote(&car_id, &1).admin_commission, 100);
+    assert_eq!(contract.get_quote(&car_id, &10).admin_commission, 500);
+    assert_eq!(contract.get_quote(&car_id, &30).admin_commission, 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")]
+pub fn test_set_commission_config_bps_above_100_percent_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.set_commission_config(&bps_config(10_001, None, None));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")]
+pub fn test_set_commission_config_max_below_min_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.set_commission_config(&bps_config(500, Some(1_000), Some(100)));
+}
@@ -1,5 +1,9 @@
-﻿use soroban_sdk::{token, Address, Env};
-use soroban_sdk::{testutils::Events, vec, Val, Vec};
+﻿use soroban_sdk::{token, vec, Address, Env, String};
+use crate::storage::structs::car_details::CarDetails;
+use crate::storage::structs::cancellation_policy::{CancellationPolicy, CancellationTier};
+use crate::storage::structs::commission_config::CommissionConfig;
+use crate::storage::types::commission_mode::CommissionMode;
+use soroban_sdk::{testutils::Events, Val, Vec};
 
 pub(crate) fn create_token_contract<'a>(
     e: &Env,
@@ -27,3 +31,34 @@ pub(crate) fn get_contract_events(
 
     contract_events
 }
+
+pub(crate) fn create_car_details(env: &Env) -> CarDetails {
+    CarDetails {
+        brand: String::from_str(env, "Toyota"),
+        model: String::from_str(env, "Corolla"),
+        color: String::from_str(env, "White"),
+        passengers: 5,
+        ac: true,
+    }
+}
+
+pub(crate) fn create_policy(env: &Env, refund_commission: bool) -> CancellationPolicy {
+    CancellationPolicy {
+        tiers: vec![
+            env,
+            CancellationTier { min_hours_before: 48, refund_bps: 10_000 },
+            CancellationTier { min_hours_before: 24, refund_bps: 5_000 },
+        ],
+        refund_commission,
+    }
+}
+
+pub(crate) fn bps_config(bps: u32, min_fee: Option<i128>, max_fee: Option<i128>) -> CommissionConfig {
+    CommissionConfig {
+        mode: CommissionMode::Bps,
+        flat: 0,
+        bps,
+        min_fee,
+        max_fee,
+    }
+}
@@ -0,0 +1,285 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::storage::{contract_balance::read_contract_balance, escrow::read_escrow_balance};
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, get_contract_events}};
+
+const DAY: u64 = 86_400;
+const CLAIM_WINDOW: u64 = 2 * DAY;
+
+#[test]
+pub fn test_return_car_holds_deposit_for_claim_window() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+    let deposit = 500_i128;
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.set_deposit_claim_window(&CLAIM_WINDOW);
+    contract.rental(&renter, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+
+    env.ledger().set_timestamp(now + DAY);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - amount - deposit);
+
+    let claim = contract.get_deposit_claim(&car_id);
+    assert_eq!(claim.renter, renter);
+    assert_eq!(claim.payer, renter);
+    assert_eq!(claim.token, token_client.address);
+    assert_eq!(claim.amount, deposit);
+    assert_eq!(claim.release_ts, now + DAY + CLAIM_WINDOW);
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, deposit);
+}
+
+#[test]
+pub fn test_withhold_deposit_after_return() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+    let deposit = 500_i128;
+    let withheld = 300_i128;
+    let reason = 4_u32;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.set_deposit_claim_window(&CLAIM_WINDOW);
+    contract.rental(&renter, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+    contract.return_car(&renter, &car_id);
+
+    contract.withhold_deposit(&car_id, &withheld, &reason);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_deposit_claim(&car_id).amount, deposit - withheld);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, amount + withheld);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, amount + withheld);
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, deposit - withheld);
+
+    assert_eq!(
+        contract_events.slice(contract_events.len() - 1..),
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "deposit_withheld").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (withheld, reason).into_val(&env)
+            )
+        ]
+    );
+
+    // The rest goes back to the renter once the owner settles the claim
+    contract.release_deposit(&car_id);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - amount - withheld);
+    assert!(contract.try_get_deposit_claim(&car_id).is_err());
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, 0);
+}
+
+#[test]
+pub fn test_anyone_can_release_deposit_after_claim_window() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+    let deposit = 500_i128;
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.set_deposit_claim_window(&CLAIM_WINDOW);
+    contract.rental(&renter, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+    contract.return_car(&renter, &car_id);
+
+    env.ledger().set_timestamp(now + CLAIM_WINDOW);
+    env.set_auths(&[]);
+    contract.release_deposit(&car_id);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - amount);
+    assert!(env.auths().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #42)")]
+pub fn test_withhold_deposit_after_claim_window_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &500);
+    contract.set_deposit_claim_window(&CLAIM_WINDOW);
+    contract.rental(&renter, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+    contract.return_car(&renter, &car_id);
+
+    env.ledger().set_timestamp(now + CLAIM_WINDOW);
+    contract.withhold_deposit(&car_id, &100, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #44)")]
+pub fn test_rental_during_claim_window_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter1 = Address::generate(&env);
+    let renter2 = Address::generate(&env);
+    let deposit = 500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter1, &10_000);
+    token_admin.mint(&renter2, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.set_deposit_claim_window(&CLAIM_WINDOW);
+    contract.rental(&renter1, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+    contract.return_car(&renter1, &car_id);
+
+    contract.rental(&renter2, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+}
+
+#[test]
+pub fn test_rental_after_claim_window_releases_claim() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter1 = Address::generate(&env);
+    let renter2 = Address::generate(&env);
+    let amount = 1500_i128;
+    let deposit = 500_i128;
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter1, &10_000);
+    token_admin.mint(&renter2, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.set_deposit_claim_window(&CLAIM_WINDOW);
+    contract.rental(&renter1, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+    contract.return_car(&renter1, &car_id);
+
+    env.ledger().set_timestamp(now + CLAIM_WINDOW);
+    contract.rental(&renter2, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+
+    assert_eq!(token_client.balance(&renter1), 10_000 - amount);
+    assert_eq!(token_client.balance(&renter2), 10_000 - amount - deposit);
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, deposit);
+}
+
+#[test]
+pub fn test_rental_after_owner_releases_claim() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter1 = Address::generate(&env);
+    let renter2 = Address::generate(&env);
+    let amount = 1500_i128;
+    let deposit = 500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter1, &10_000);
+    token_admin.mint(&renter2, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.set_deposit_claim_window(&CLAIM_WINDOW);
+    contract.rental(&renter1, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+    contract.return_car(&renter1, &car_id);
+
+    contract.release_deposit(&car_id);
+    contract.rental(&renter2, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+
+    assert_eq!(token_client.balance(&renter1), 10_000 - amount);
+    assert_eq!(token_client.balance(&renter2), 10_000 - amount - deposit);
+}
+
+#[test]
+pub fn test_return_car_without_claim_window_refunds_deposit() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+    let deposit = 500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.rental(&renter, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - amount);
+    assert!(contract.try_get_deposit_claim(&car_id).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #41)")]
+pub fn test_get_deposit_claim_without_claim_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.get_deposit_claim(&car_id);
+}
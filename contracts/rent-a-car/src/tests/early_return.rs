@@ -0,0 +1,184 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::storage::{contract_balance::read_contract_balance};
+use crate::storage::structs::early_return_policy::EarlyReturnPolicy;
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, get_contract_events}};
+use crate::storage::structs::earnings_statement::EarningsStatement;
+
+const DAY: u64 = 86_400;
+
+#[test]
+pub fn test_set_car_early_return_policy_successfully() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let policy = EarlyReturnPolicy { enabled: true, fee: 200 };
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_early_return_policy(&car_id, &policy);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "early_return_policy_set").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                policy.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_early_return_refunds_unused_full_days_minus_fee() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1000_i128;
+    let deposit = 500_i128;
+    let fee = 200_i128;
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.set_car_early_return_policy(&car_id, &EarlyReturnPolicy { enabled: true, fee });
+    contract.rental(&renter, &car_id, &7, &contract.get_quote(&car_id, &7).total, &token_client.address);
+
+    // Back after 2 days and a bit: 4 full days are unused
+    let returned_ts = now + 2 * DAY + 3_600;
+    env.ledger().set_timestamp(returned_ts);
+
+    contract.return_car(&renter, &car_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let early_refund = 4 * price_per_day - fee;
+    assert_eq!(token_client.balance(&renter), amount_mint - 7000 + early_refund);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, 7000 - early_refund);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, 7000 - early_refund);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "statement_entry").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (
+                    token_client.address.clone(),
+                    EarningsStatement { rental_count: 0, total_earned: 0, total_refunded: early_refund, total_withdrawn: 0, fees_paid: 0 },
+                ).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "deposit_refunded").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                deposit.into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_returned").as_val(),
+                    renter.clone().into_val(&env),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (token_client.address.clone(), returned_ts, 0_i128, early_refund).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_early_return_without_policy_has_no_refund() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&owner, &1000_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &7, &7000, &token_client.address);
+
+    env.ledger().set_timestamp(now + DAY);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - 7000);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, 7000);
+}
+
+#[test]
+pub fn test_early_return_fee_above_refund_gives_nothing() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&owner, &1000_i128, &create_car_details(&env));
+    contract.set_car_early_return_policy(&car_id, &EarlyReturnPolicy { enabled: true, fee: 1500 });
+    contract.rental(&renter, &car_id, &2, &2000, &token_client.address);
+
+    env.ledger().set_timestamp(now + 3_600);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - 2000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+pub fn test_set_car_early_return_policy_negative_fee_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_early_return_policy(&car_id, &EarlyReturnPolicy { enabled: true, fee: -1 });
+}
@@ -0,0 +1,191 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::storage::{contract_balance::read_contract_balance, rental::read_rental};
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, get_contract_events}};
+use crate::storage::structs::earnings_statement::EarningsStatement;
+
+const DAY: u64 = 86_400;
+
+#[test]
+pub fn test_extend_rental_successfully() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let commission = 100_i128;
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    contract.set_admin_commission(&commission);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &2, &contract.get_quote(&car_id, &2).total, &token_client.address);
+
+    env.ledger().set_timestamp(now + DAY);
+    contract.extend_rental(&renter, &car_id, &1);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let due_ts = now + 3 * DAY;
+    assert_eq!(token_client.balance(&renter), amount_mint - 3 * price_per_day - 2 * commission);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, car_id)).unwrap();
+    assert_eq!(rental.total_days_to_rent, 3);
+    assert_eq!(rental.amount, 3 * price_per_day);
+    assert_eq!(rental.admin_commission, 2 * commission);
+    assert_eq!(rental.start_ts, now);
+    assert_eq!(rental.due_ts, due_ts);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.pending, 3 * price_per_day);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), 2 * commission);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, 3 * price_per_day + 2 * commission);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "statement_entry").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (
+                    token_client.address.clone(),
+                    EarningsStatement { rental_count: 0, total_earned: price_per_day, total_refunded: 0, total_withdrawn: 0, fees_paid: 0 },
+                ).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "rental_extended").as_val(),
+                    renter.clone().into_val(&env),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (1_u32, due_ts, price_per_day).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_extend_rental_up_to_next_booking() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter_1 = Address::generate(&env);
+    let renter_2 = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter_1, &10_000_i128);
+    token_admin.mint(&renter_2, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter_1, &car_id, &1, &1500, &token_client.address);
+    contract.reserve(&renter_2, &car_id, &(now + 2 * DAY), &(now + 3 * DAY), &token_client.address);
+
+    contract.extend_rental(&renter_1, &car_id, &1);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, car_id)).unwrap();
+    assert_eq!(rental.due_ts, now + 2 * DAY);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+pub fn test_extend_rental_into_booking_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter_1 = Address::generate(&env);
+    let renter_2 = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter_1, &10_000_i128);
+    token_admin.mint(&renter_2, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter_1, &car_id, &1, &1500, &token_client.address);
+    contract.reserve(&renter_2, &car_id, &(now + 2 * DAY), &(now + 3 * DAY), &token_client.address);
+
+    contract.extend_rental(&renter_1, &car_id, &2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")]
+pub fn test_extend_overdue_rental_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+
+    env.ledger().set_timestamp(now + DAY);
+    contract.extend_rental(&renter, &car_id, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+pub fn test_extend_rental_zero_days_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+
+    contract.extend_rental(&renter, &car_id, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+pub fn test_extend_rental_by_other_renter_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let other_renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+
+    contract.extend_rental(&other_renter, &car_id, &1);
+}
@@ -0,0 +1,279 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::storage::{contract_balance::read_contract_balance, escrow::read_escrow_balance, rental::read_rental};
+use crate::storage::structs::cancellation_policy::{CancellationPolicy, CancellationTier};
+use crate::storage::types::fee_bearer::FeeBearer;
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, get_contract_events}};
+use crate::storage::structs::earnings_statement::EarningsStatement;
+
+const DAY: u64 = 86_400;
+
+#[test]
+pub fn test_set_fee_bearer_successfully() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    assert_eq!(contract.get_fee_bearer(&owner), FeeBearer::Renter);
+
+    contract.set_fee_bearer(&FeeBearer::Owner);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_fee_bearer(&owner), FeeBearer::Owner);
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![&env, *Symbol::new(&env, "fee_bearer_set").as_val()],
+                FeeBearer::Owner.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_owner_fee_bearer_overrides_global() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.set_fee_bearer(&FeeBearer::Owner);
+    contract.set_owner_fee_bearer(&owner, &FeeBearer::Renter);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_fee_bearer(&owner), FeeBearer::Renter);
+    assert_eq!(contract.get_fee_bearer(&other_owner), FeeBearer::Owner);
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "owner_fee_bearer_set").as_val(),
+                    owner.clone().into_val(&env),
+                ],
+                FeeBearer::Renter.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_rental_with_owner_paid_commission() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let total_days = 3;
+    let amount = 4500_i128;
+    let commission = 500_i128;
+    let amount_mint = 10_000_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    contract.set_admin_commission(&commission);
+    contract.set_owner_fee_bearer(&owner, &FeeBearer::Owner);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    // The renter pays the all-inclusive price, the commission comes out of the owner share
+    assert_eq!(token_client.balance(&renter), amount_mint - amount);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.pending, amount - commission);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), commission);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, amount);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, car_id)).unwrap();
+    assert_eq!(rental.fee_bearer, FeeBearer::Owner);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "statement_entry").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (
+                    token_client.address.clone(),
+                    EarningsStatement { rental_count: 1, total_earned: amount - commission, total_refunded: 0, total_withdrawn: 0, fees_paid: commission },
+                ).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "rented").as_val(),
+                    renter.clone().into_val(&env),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (token_client.address.clone(), total_days, amount, amount - commission, commission).into_val(&env)
+            )
+        ]
+    );
+
+    contract.return_car(&renter, &car_id);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, amount - commission);
+}
+
+#[test]
+pub fn test_owner_paid_commission_is_capped_at_rental_amount() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.set_admin_commission(&2000);
+    contract.set_fee_bearer(&FeeBearer::Owner);
+    let car_id = contract.add_car(&owner, &amount, &create_car_details(&env));
+
+    contract.rental(&renter, &car_id, &1, &amount, &token_client.address);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - amount);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), amount);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.pending, 0);
+}
+
+#[test]
+pub fn test_fee_bearer_change_does_not_affect_active_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission = 100_i128;
+    let amount_mint = 10_000_i128;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000_000);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    contract.set_admin_commission(&commission);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+
+    // The extension is charged with the fee bearer the rental started with
+    contract.set_fee_bearer(&FeeBearer::Owner);
+    contract.extend_rental(&renter, &car_id, &1);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - 2 * (1500 + commission));
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.pending, 3000);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), 2 * commission);
+}
+
+#[test]
+pub fn test_reservation_with_owner_paid_commission() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission = 200_i128;
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+    let start_ts = now + DAY;
+    let end_ts = start_ts + 2 * DAY;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    contract.set_admin_commission(&commission);
+    contract.set_owner_fee_bearer(&owner, &FeeBearer::Owner);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    let booking_id = contract.reserve(&renter, &car_id, &start_ts, &end_ts, &token_client.address);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - 3000);
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, 3000);
+
+    env.ledger().set_timestamp(start_ts);
+    contract.pickup(&renter, &car_id, &booking_id);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.pending, 3000 - commission);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), commission);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, 3000);
+}
+
+#[test]
+pub fn test_cancel_reservation_with_owner_paid_commission() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 3000_i128;
+    let commission = 200_i128;
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+
+    let policy = CancellationPolicy {
+        tiers: vec![&env, CancellationTier { min_hours_before: 0, refund_bps: 5_000 }],
+        refund_commission: false,
+    };
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    contract.set_admin_commission(&commission);
+    contract.set_owner_fee_bearer(&owner, &FeeBearer::Owner);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_cancellation_policy(&car_id, &policy);
+    let booking_id = contract.reserve(&renter, &car_id, &(now + 2 * DAY), &(now + 4 * DAY), &token_client.address);
+
+    env.ledger().set_timestamp(now + DAY);
+    contract.cancel_reservation(&renter, &car_id, &booking_id);
+
+    // Half of the owner share goes back, the admin keeps the commission
+    let refund = (amount - commission) / 2;
+    assert_eq!(token_client.balance(&renter), amount_mint - amount + refund);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, amount - commission - refund);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), commission);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, amount - refund);
+}
@@ -1,11 +1,13 @@
+use crate::tests::config::utils::create_car_details;
 use soroban_sdk::{testutils::Address as _, Address};
 use crate::tests::config::contract::ContractTest;
 
 #[test]
 pub fn test_get_admin_available_to_withdraw_default_zero() {
-    let ContractTest { contract, .. } = ContractTest::setup();
+    let ContractTest { contract, token, .. } = ContractTest::setup();
+    let (token_client, _, _) = token;
 
-    let available = contract.get_admin_available_to_withdraw();
+    let available = contract.get_admin_available_to_withdraw(&token_client.address);
     assert_eq!(available, 0);
 }
 
@@ -17,27 +19,26 @@ pub fn test_get_admin_available_to_withdraw_after_rental() {
     let renter = Address::generate(&env);
     let price_per_day = 1500_i128;
     let total_days = 3;
-    let amount = 4500_i128;
     let commission = 500_i128;
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
     contract.set_admin_commission(&commission);
 
     // Before rental, available should be 0
-    let available = contract.get_admin_available_to_withdraw();
+    let available = contract.get_admin_available_to_withdraw(&token_client.address);
     assert_eq!(available, 0);
 
     // After rental, commission should be available
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &contract.get_quote(&car_id, &total_days).total, &token_client.address);
 
-    let available = contract.get_admin_available_to_withdraw();
+    let available = contract.get_admin_available_to_withdraw(&token_client.address);
     assert_eq!(available, commission);
 }
 
@@ -51,26 +52,25 @@ pub fn test_get_admin_available_to_withdraw_after_multiple_rentals() {
     let renter2 = Address::generate(&env);
     let price_per_day = 1500_i128;
     let total_days = 3;
-    let amount1 = 4500_i128;
-    let amount2 = 3000_i128;
+    let total_days2 = 2;
     let commission = 500_i128;
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter1, &amount_mint);
     token_admin.mint(&renter2, &amount_mint);
 
-    contract.add_car(&owner1, &price_per_day);
-    contract.add_car(&owner2, &price_per_day);
+    let car_id1 = contract.add_car(&owner1, &price_per_day, &create_car_details(&env));
+    let car_id2 = contract.add_car(&owner2, &price_per_day, &create_car_details(&env));
     contract.set_admin_commission(&commission);
 
-    contract.rental(&renter1, &owner1, &total_days, &amount1);
-    contract.rental(&renter2, &owner2, &total_days, &amount2);
+    contract.rental(&renter1, &car_id1, &total_days, &contract.get_quote(&car_id1, &total_days).total, &token_client.address);
+    contract.rental(&renter2, &car_id2, &total_days2, &contract.get_quote(&car_id2, &total_days2).total, &token_client.address);
 
-    let available = contract.get_admin_available_to_withdraw();
+    let available = contract.get_admin_available_to_withdraw(&token_client.address);
     assert_eq!(available, commission * 2);
 }
 
@@ -82,27 +82,26 @@ pub fn test_get_admin_available_to_withdraw_after_withdraw() {
     let renter = Address::generate(&env);
     let price_per_day = 1500_i128;
     let total_days = 3;
-    let amount = 4500_i128;
     let commission = 500_i128;
     let withdraw_amount = 300_i128;
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
     contract.set_admin_commission(&commission);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &contract.get_quote(&car_id, &total_days).total, &token_client.address);
 
-    let available_before = contract.get_admin_available_to_withdraw();
+    let available_before = contract.get_admin_available_to_withdraw(&token_client.address);
     assert_eq!(available_before, commission);
 
-    contract.withdraw_admin_commission(&withdraw_amount);
+    contract.withdraw_admin_commission(&token_client.address, &withdraw_amount);
 
-    let available_after = contract.get_admin_available_to_withdraw();
+    let available_after = contract.get_admin_available_to_withdraw(&token_client.address);
     assert_eq!(available_after, commission - withdraw_amount);
 }
 
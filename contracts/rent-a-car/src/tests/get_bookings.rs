@@ -0,0 +1,56 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
+
+const DAY: u64 = 86_400;
+
+#[test]
+pub fn test_get_bookings_returns_overlapping_bookings_only() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &20_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    let booking_id_1 = contract.reserve(&renter, &car_id, &(now + DAY), &(now + 2 * DAY), &token_client.address);
+    let booking_id_2 = contract.reserve(&renter, &car_id, &(now + 5 * DAY), &(now + 6 * DAY), &token_client.address);
+
+    let bookings = contract.get_bookings(&car_id, &(now + DAY), &(now + 3 * DAY));
+    assert_eq!(bookings.len(), 1);
+    assert_eq!(bookings.get(0).unwrap().booking_id, booking_id_1);
+
+    let bookings = contract.get_bookings(&car_id, &(now + 2 * DAY), &(now + 5 * DAY));
+    assert_eq!(bookings.len(), 0);
+
+    let bookings = contract.get_bookings(&car_id, &now, &(now + 10 * DAY));
+    assert_eq!(bookings.len(), 2);
+    assert_eq!(bookings.get(1).unwrap().booking_id, booking_id_2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+pub fn test_get_bookings_car_not_found() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    contract.get_bookings(&0, &0, &86_400);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+pub fn test_get_bookings_invalid_range() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.get_bookings(&car_id, &86_400, &86_400);
+}
@@ -0,0 +1,29 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::{storage::types::car_status::CarStatus, tests::config::{contract::ContractTest, utils::create_car_details}};
+
+#[test]
+pub fn test_get_car_returns_stored_car() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let details = create_car_details(&env);
+
+    let car_id = contract.add_car(&owner, &price_per_day, &details);
+
+    let car = contract.get_car(&car_id);
+    assert_eq!(car.owner, owner);
+    assert_eq!(car.details, details);
+    assert_eq!(car.price_per_day, price_per_day);
+    assert_eq!(car.car_status, CarStatus::Available);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+pub fn test_get_car_not_found_fails() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    contract.get_car(&0);
+}
@@ -1,4 +1,5 @@
-﻿use soroban_sdk::{testutils::Address as _, Address};
+﻿use crate::tests::config::utils::create_car_details;
+use soroban_sdk::{testutils::Address as _, Address};
 use crate::{storage::{types::car_status::CarStatus}, tests::config::contract::ContractTest};
 
 #[test]
@@ -10,17 +11,17 @@ pub fn test_get_car_status_returns_available() {
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
 
-    let status = contract.get_car_status(&owner);
+    let status = contract.get_car_status(&car_id);
     assert_eq!(status, CarStatus::Available);
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #2)")]
 pub fn test_get_car_status_car_not_found_fails() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
-    let owner = Address::generate(&env);
+    let ContractTest { contract, .. } = ContractTest::setup();
+    let car_id = 0_u32;
 
-    contract.get_car_status(&owner);
+    contract.get_car_status(&car_id);
 }
\ No newline at end of file
@@ -1,19 +1,21 @@
+use crate::tests::config::utils::create_car_details;
 use soroban_sdk::{testutils::Address as _, Address};
 use crate::tests::config::contract::ContractTest;
 
 #[test]
 pub fn test_get_owner_available_to_withdraw_car_not_found() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, token, .. } = ContractTest::setup();
+    let (token_client, _, _) = token;
 
     let owner = Address::generate(&env);
     
     // Should return 0 when car doesn't exist
-    let available = contract.get_owner_available_to_withdraw(&owner);
+    let available = contract.get_owner_available_to_withdraw(&owner, &token_client.address).settled;
     assert_eq!(available, 0);
 }
 
 #[test]
-pub fn test_get_owner_available_to_withdraw_car_rented() {
+pub fn test_get_owner_available_to_withdraw_car_rented_is_pending() {
     let ContractTest { env, contract, token, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
@@ -24,32 +26,34 @@ pub fn test_get_owner_available_to_withdraw_car_rented() {
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
 
-    // Should return 0 when car is rented
-    let available = contract.get_owner_available_to_withdraw(&owner);
-    assert_eq!(available, 0, "Should return 0 when car is rented");
+    // The rental stays pending while the car is rented
+    let balance = contract.get_owner_available_to_withdraw(&owner, &token_client.address);
+    assert_eq!(balance.settled, 0, "Nothing is settled while the car is rented");
+    assert_eq!(balance.pending, amount);
 }
 
 #[test]
 pub fn test_get_owner_available_to_withdraw_car_available_no_funds() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, token, .. } = ContractTest::setup();
+    let (token_client, _, _) = token;
 
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
 
     env.mock_all_auths();
 
-    contract.add_car(&owner, &price_per_day);
+    contract.add_car(&owner, &price_per_day, &create_car_details(&env));
 
     // Should return 0 when car is available but has no funds
-    let available = contract.get_owner_available_to_withdraw(&owner);
+    let available = contract.get_owner_available_to_withdraw(&owner, &token_client.address).settled;
     assert_eq!(available, 0, "Should return 0 when no funds available");
 }
 
@@ -65,19 +69,19 @@ pub fn test_get_owner_available_to_withdraw_car_available_with_funds() {
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
     
     // Return the car first
-    contract.return_car(&renter, &owner);
+    contract.return_car(&renter, &car_id);
 
     // Should return the available amount when car is available and has funds
-    let available = contract.get_owner_available_to_withdraw(&owner);
+    let available = contract.get_owner_available_to_withdraw(&owner, &token_client.address).settled;
     assert_eq!(available, amount, "Should return the available amount when car is returned");
 }
 
@@ -94,24 +98,24 @@ pub fn test_get_owner_available_to_withdraw_after_partial_withdraw() {
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
-    contract.return_car(&renter, &owner);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
+    contract.return_car(&renter, &car_id);
 
     // Check available before withdrawal
-    let available_before = contract.get_owner_available_to_withdraw(&owner);
+    let available_before = contract.get_owner_available_to_withdraw(&owner, &token_client.address).settled;
     assert_eq!(available_before, amount);
 
     // Withdraw partial amount
-    contract.payout_owner(&owner, &withdraw_amount);
+    contract.payout_owner(&car_id, &token_client.address, &withdraw_amount);
 
     // Check available after withdrawal
-    let available_after = contract.get_owner_available_to_withdraw(&owner);
+    let available_after = contract.get_owner_available_to_withdraw(&owner, &token_client.address).settled;
     assert_eq!(available_after, amount - withdraw_amount, 
         "Should return remaining amount after partial withdrawal");
 }
@@ -131,25 +135,61 @@ pub fn test_get_owner_available_to_withdraw_multiple_rentals() {
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter1, &amount_mint);
     token_admin.mint(&renter2, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
     
     // First rental
-    contract.rental(&renter1, &owner, &total_days1, &amount1);
-    contract.return_car(&renter1, &owner);
+    contract.rental(&renter1, &car_id, &total_days1, &amount1, &token_client.address);
+    contract.return_car(&renter1, &car_id);
 
     // Second rental
-    contract.rental(&renter2, &owner, &total_days2, &amount2);
-    contract.return_car(&renter2, &owner);
+    contract.rental(&renter2, &car_id, &total_days2, &amount2, &token_client.address);
+    contract.return_car(&renter2, &car_id);
 
     // Should return total accumulated funds
-    let available = contract.get_owner_available_to_withdraw(&owner);
+    let available = contract.get_owner_available_to_withdraw(&owner, &token_client.address).settled;
     assert_eq!(available, amount1 + amount2, 
         "Should return total accumulated funds from multiple rentals");
 }
 
+
+#[test]
+pub fn test_get_owner_available_to_withdraw_multiple_cars() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter1 = Address::generate(&env);
+    let renter2 = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+
+    let amount_mint = 10_000_i128;
+    token_admin.mint(&renter1, &amount_mint);
+    token_admin.mint(&renter2, &amount_mint);
+
+    let car_id1 = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    let car_id2 = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+
+    contract.rental(&renter1, &car_id1, &total_days, &amount, &token_client.address);
+    contract.return_car(&renter1, &car_id1);
+    contract.rental(&renter2, &car_id2, &total_days, &amount, &token_client.address);
+
+    // Only the returned car counts towards the owner's available funds
+    let available = contract.get_owner_available_to_withdraw(&owner, &token_client.address).settled;
+    assert_eq!(available, amount);
+
+    contract.return_car(&renter2, &car_id2);
+
+    let available = contract.get_owner_available_to_withdraw(&owner, &token_client.address).settled;
+    assert_eq!(available, amount * 2);
+}
@@ -0,0 +1,48 @@
+use crate::tests::config::utils::create_car_details;
+use soroban_sdk::{testutils::Address as _, vec, Address};
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+pub fn test_get_owner_cars_returns_all_owner_cars() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+
+    env.mock_all_auths();
+
+    let car_id1 = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    let other_car_id = contract.add_car(&other_owner, &price_per_day, &create_car_details(&env));
+    let car_id2 = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+
+    assert_eq!(contract.get_owner_cars(&owner), vec![&env, car_id1, car_id2]);
+    assert_eq!(contract.get_owner_cars(&other_owner), vec![&env, other_car_id]);
+}
+
+#[test]
+pub fn test_get_owner_cars_keeps_retired_cars() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+
+    env.mock_all_auths();
+
+    let car_id1 = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    let car_id2 = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+
+    contract.remove_car(&car_id1);
+
+    // Retired cars stay listed so the owner can still withdraw their balance
+    assert_eq!(contract.get_owner_cars(&owner), vec![&env, car_id1, car_id2]);
+}
+
+#[test]
+pub fn test_get_owner_cars_without_cars_is_empty() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    assert!(contract.get_owner_cars(&owner).is_empty());
+}
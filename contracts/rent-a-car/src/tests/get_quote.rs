@@ -0,0 +1,107 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::storage::structs::rental_quote::RentalQuote;
+use crate::storage::types::fee_bearer::FeeBearer;
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
+
+#[test]
+pub fn test_get_quote_without_commission() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+
+    let quote = contract.get_quote(&car_id, &3);
+    assert_eq!(
+        quote,
+        RentalQuote {
+            base_price: 4500,
+            admin_commission: 0,
+            fee_bearer: FeeBearer::Renter,
+            owner_amount: 4500,
+            deposit: 0,
+            total: 4500,
+        }
+    );
+}
+
+#[test]
+pub fn test_get_quote_includes_admin_commission() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let commission = 500_i128;
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.set_admin_commission(&commission);
+
+    let quote = contract.get_quote(&car_id, &2);
+    assert_eq!(quote.base_price, 3000);
+    assert_eq!(quote.admin_commission, commission);
+    assert_eq!(quote.total, 3000 + commission);
+}
+
+#[test]
+pub fn test_get_quote_with_owner_paid_commission() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let commission = 500_i128;
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_admin_commission(&commission);
+    contract.set_owner_fee_bearer(&owner, &FeeBearer::Owner);
+
+    let quote = contract.get_quote(&car_id, &2);
+    assert_eq!(quote.fee_bearer, FeeBearer::Owner);
+    assert_eq!(quote.admin_commission, commission);
+    assert_eq!(quote.owner_amount, 3000 - commission);
+    assert_eq!(quote.total, 3000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+pub fn test_get_quote_with_zero_days_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract.get_quote(&car_id, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+pub fn test_get_quote_car_not_found_fails() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    contract.get_quote(&0, &3);
+}
+
+#[test]
+pub fn test_get_quote_includes_deposit() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let deposit = 1000_i128;
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+
+    let quote = contract.get_quote(&car_id, &2);
+    assert_eq!(quote.base_price, 3000);
+    assert_eq!(quote.deposit, deposit);
+    assert_eq!(quote.total, 3000 + deposit);
+}
@@ -0,0 +1,101 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::storage::structs::rental_time_status::RentalTimeStatus;
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
+
+const DAY: u64 = 86_400;
+
+#[test]
+pub fn test_get_rental_records_start_and_due_time() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+    let start_ts = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(start_ts);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
+
+    let rental = contract.get_rental(&car_id);
+    assert_eq!(rental.renter, renter);
+    assert_eq!(rental.start_ts, start_ts);
+    assert_eq!(rental.due_ts, start_ts + 3 * DAY);
+}
+
+#[test]
+pub fn test_get_rental_time_status_before_due() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let start_ts = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(start_ts);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &3, &4500_i128, &token_client.address);
+
+    env.ledger().set_timestamp(start_ts + DAY);
+
+    let status = contract.get_rental_time_status(&car_id);
+    assert_eq!(
+        status,
+        RentalTimeStatus {
+            start_ts,
+            due_ts: start_ts + 3 * DAY,
+            remaining_secs: 2 * DAY,
+            overdue: false,
+            overdue_secs: 0,
+        }
+    );
+}
+
+#[test]
+pub fn test_get_rental_time_status_overdue() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let start_ts = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(start_ts);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &1500_i128, &token_client.address);
+
+    env.ledger().set_timestamp(start_ts + DAY + 3_600);
+
+    let status = contract.get_rental_time_status(&car_id);
+    assert_eq!(status.remaining_secs, 0);
+    assert!(status.overdue);
+    assert_eq!(status.overdue_secs, 3_600);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+pub fn test_get_rental_without_rental_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract.get_rental(&car_id);
+}
@@ -0,0 +1,188 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::storage::{contract_balance::read_contract_balance, escrow::read_escrow_balance};
+use crate::storage::types::late_fee_unit::LateFeeUnit;
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, get_contract_events}};
+use crate::storage::structs::earnings_statement::EarningsStatement;
+
+const DAY: u64 = 86_400;
+const HOUR: u64 = 3_600;
+
+#[test]
+pub fn test_late_return_penalty_taken_from_deposit() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 3000_i128;
+    let deposit = 1000_i128;
+    let late_fee = 100_i128;
+    let amount_mint = 10_000_i128;
+    let start_ts = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(start_ts);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.set_car_late_fee(&car_id, &late_fee, &LateFeeUnit::Hour);
+    contract.rental(&renter, &car_id, &2, &contract.get_quote(&car_id, &2).total, &token_client.address);
+
+    // 2 hours and a bit late: three started hours are charged
+    let returned_ts = start_ts + 2 * DAY + 2 * HOUR + 60;
+    env.ledger().set_timestamp(returned_ts);
+
+    contract.return_car(&renter, &car_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let penalty = late_fee * 3;
+    assert_eq!(token_client.balance(&renter), amount_mint - amount - penalty);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, amount + penalty);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, amount + penalty);
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, 0);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "statement_entry").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (
+                    token_client.address.clone(),
+                    EarningsStatement { rental_count: 0, total_earned: penalty, total_refunded: 0, total_withdrawn: 0, fees_paid: 0 },
+                ).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "deposit_refunded").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (deposit - penalty).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_returned").as_val(),
+                    renter.clone().into_val(&env),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (token_client.address.clone(), returned_ts, penalty, 0_i128).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_late_return_penalty_beyond_deposit_is_pulled_from_renter() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+    let deposit = 200_i128;
+    let late_fee = 500_i128;
+    let amount_mint = 10_000_i128;
+    let start_ts = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(start_ts);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.set_car_late_fee(&car_id, &late_fee, &LateFeeUnit::Day);
+    contract.rental(&renter, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+
+    // One day and an hour late: two started days are charged
+    env.ledger().set_timestamp(start_ts + 2 * DAY + HOUR);
+
+    contract.return_car(&renter, &car_id);
+
+    let penalty = late_fee * 2;
+    assert_eq!(token_client.balance(&renter), amount_mint - amount - penalty);
+    assert_eq!(token_client.balance(&contract.address), amount + penalty);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, amount + penalty);
+}
+
+#[test]
+pub fn test_late_return_within_grace_period_is_free() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+    let amount_mint = 10_000_i128;
+    let start_ts = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(start_ts);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_late_fee(&car_id, &100, &LateFeeUnit::Hour);
+    contract.set_late_grace_period(&HOUR);
+    contract.rental(&renter, &car_id, &1, &amount, &token_client.address);
+
+    env.ledger().set_timestamp(start_ts + DAY + HOUR);
+
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - amount);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, amount);
+}
+
+#[test]
+pub fn test_late_fee_change_does_not_affect_active_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+    let amount_mint = 10_000_i128;
+    let start_ts = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(start_ts);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_late_fee(&car_id, &100, &LateFeeUnit::Hour);
+    contract.rental(&renter, &car_id, &1, &amount, &token_client.address);
+
+    contract.set_car_late_fee(&car_id, &1000, &LateFeeUnit::Hour);
+
+    env.ledger().set_timestamp(start_ts + DAY + HOUR);
+
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - amount - 100);
+}
@@ -0,0 +1,193 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, Vec};
+use crate::storage::structs::car_filter::CarFilter;
+use crate::storage::types::car_status::CarStatus;
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
+
+fn empty_filter(env: &Env) -> CarFilter {
+    CarFilter {
+        statuses: Vec::new(env),
+        min_price_per_day: None,
+        max_price_per_day: None,
+        owner: None,
+    }
+}
+
+#[test]
+pub fn test_list_cars_returns_all_cars() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    let details = create_car_details(&env);
+
+    let car_id1 = contract.add_car(&owner1, &1500_i128, &details);
+    let car_id2 = contract.add_car(&owner2, &2000_i128, &details);
+
+    let page = contract.list_cars(&0, &10, &empty_filter(&env));
+
+    assert_eq!(page.cars.len(), 2);
+    assert_eq!(page.next_cursor, None);
+
+    let first = page.cars.get(0).unwrap();
+    assert_eq!(first.car_id, car_id1);
+    assert_eq!(first.car.owner, owner1);
+    assert_eq!(first.car.car_status, CarStatus::Available);
+
+    let second = page.cars.get(1).unwrap();
+    assert_eq!(second.car_id, car_id2);
+    assert_eq!(second.car.owner, owner2);
+}
+
+#[test]
+pub fn test_list_cars_paginates_with_cursor() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let details = create_car_details(&env);
+
+    let car_id1 = contract.add_car(&owner, &1500_i128, &details);
+    let car_id2 = contract.add_car(&owner, &1500_i128, &details);
+    let car_id3 = contract.add_car(&owner, &1500_i128, &details);
+
+    let first_page = contract.list_cars(&0, &2, &empty_filter(&env));
+    assert_eq!(first_page.cars.len(), 2);
+    assert_eq!(first_page.cars.get(0).unwrap().car_id, car_id1);
+    assert_eq!(first_page.cars.get(1).unwrap().car_id, car_id2);
+    assert_eq!(first_page.next_cursor, Some(car_id3));
+
+    let second_page = contract.list_cars(&first_page.next_cursor.unwrap(), &2, &empty_filter(&env));
+    assert_eq!(second_page.cars.len(), 1);
+    assert_eq!(second_page.cars.get(0).unwrap().car_id, car_id3);
+    assert_eq!(second_page.next_cursor, None);
+}
+
+#[test]
+pub fn test_list_cars_filters_by_status_price_and_owner() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let details = create_car_details(&env);
+
+    token_admin.mint(&renter, &10_000_i128);
+
+    let cheap_car = contract.add_car(&owner1, &1000_i128, &details);
+    let rented_car = contract.add_car(&owner1, &1500_i128, &details);
+    let expensive_car = contract.add_car(&owner2, &5000_i128, &details);
+
+    contract.rental(&renter, &rented_car, &2, &3000_i128, &token_client.address);
+
+    let mut filter = empty_filter(&env);
+    filter.statuses = vec![&env, CarStatus::Available];
+    let page = contract.list_cars(&0, &10, &filter);
+    assert_eq!(page.cars.len(), 2);
+    assert_eq!(page.cars.get(0).unwrap().car_id, cheap_car);
+    assert_eq!(page.cars.get(1).unwrap().car_id, expensive_car);
+
+    let mut filter = empty_filter(&env);
+    filter.min_price_per_day = Some(1200);
+    filter.max_price_per_day = Some(4000);
+    let page = contract.list_cars(&0, &10, &filter);
+    assert_eq!(page.cars.len(), 1);
+    assert_eq!(page.cars.get(0).unwrap().car_id, rented_car);
+
+    let mut filter = empty_filter(&env);
+    filter.owner = Some(owner2.clone());
+    let page = contract.list_cars(&0, &10, &filter);
+    assert_eq!(page.cars.len(), 1);
+    assert_eq!(page.cars.get(0).unwrap().car.owner, owner2);
+}
+
+#[test]
+pub fn test_list_cars_skips_retired_cars() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let details = create_car_details(&env);
+
+    let car_id1 = contract.add_car(&owner, &1500_i128, &details);
+    let car_id2 = contract.add_car(&owner, &1500_i128, &details);
+
+    contract.remove_car(&car_id1);
+
+    let page = contract.list_cars(&0, &10, &empty_filter(&env));
+    assert_eq!(page.cars.len(), 1);
+    assert_eq!(page.cars.get(0).unwrap().car_id, car_id2);
+}
+
+#[test]
+pub fn test_list_cars_pages_across_index_chunks() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let details = create_car_details(&env);
+
+    for _ in 0..60 {
+        contract.add_car(&owner, &1500_i128, &details);
+    }
+
+    let first_page = contract.list_cars(&0, &50, &empty_filter(&env));
+    assert_eq!(first_page.cars.len(), 50);
+    assert_eq!(first_page.next_cursor, Some(50));
+
+    let second_page = contract.list_cars(&50, &50, &empty_filter(&env));
+    assert_eq!(second_page.cars.len(), 10);
+    assert_eq!(second_page.cars.get(0).unwrap().car_id, 50);
+    assert_eq!(second_page.next_cursor, None);
+}
+
+#[test]
+pub fn test_list_cars_bounds_entries_scanned_per_call() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let details = create_car_details(&env);
+
+    for _ in 0..120 {
+        contract.add_car(&owner, &1000_i128, &details);
+    }
+    let expensive_car = contract.add_car(&owner, &5000_i128, &details);
+
+    let mut filter = empty_filter(&env);
+    filter.min_price_per_day = Some(5000);
+
+    // The first call runs out of scan budget before reaching the only match
+    let first_page = contract.list_cars(&0, &10, &filter);
+    assert!(first_page.cars.is_empty());
+    assert!(first_page.next_cursor.is_some());
+
+    let mut found = Vec::new(&env);
+    let mut cursor = first_page.next_cursor;
+
+    while let Some(next) = cursor {
+        let page = contract.list_cars(&next, &10, &filter);
+        found.append(&page.cars);
+        cursor = page.next_cursor;
+    }
+
+    assert_eq!(found.len(), 1);
+    assert_eq!(found.get(0).unwrap().car_id, expensive_car);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_list_cars_with_zero_limit_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    contract.list_cars(&0, &0, &empty_filter(&env));
+}
@@ -0,0 +1,196 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, String, Symbol};
+use crate::storage::types::car_status::CarStatus;
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, get_contract_events}};
+
+const HOUR: u64 = 3_600;
+
+#[test]
+pub fn test_start_and_end_maintenance_successfully() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let reason = String::from_str(&env, "Oil change");
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract.start_maintenance(&owner, &car_id, &reason);
+    let start_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Maintenance);
+    assert_eq!(
+        start_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "maintenance_started").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                reason.clone().into_val(&env)
+            )
+        ]
+    );
+
+    env.ledger().set_timestamp(now + 5 * HOUR);
+    contract.end_maintenance(&owner, &car_id);
+    let end_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Available);
+    assert_eq!(
+        end_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "maintenance_ended").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (5 * HOUR).into_val(&env)
+            )
+        ]
+    );
+
+    let log = contract.get_maintenance_log(&car_id);
+    assert_eq!(log.len(), 1);
+
+    let record = log.get(0).unwrap();
+    assert_eq!(record.reason, reason);
+    assert_eq!(record.start_ts, now);
+    assert_eq!(record.end_ts, now + 5 * HOUR);
+}
+
+#[test]
+pub fn test_admin_can_manage_maintenance() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract.start_maintenance(&admin, &car_id, &String::from_str(&env, "Inspection"));
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Maintenance);
+
+    contract.end_maintenance(&admin, &car_id);
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Available);
+}
+
+#[test]
+pub fn test_maintenance_downtime_adds_up_entries() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract.start_maintenance(&owner, &car_id, &String::from_str(&env, "Tires"));
+    env.ledger().set_timestamp(now + 2 * HOUR);
+    contract.end_maintenance(&owner, &car_id);
+
+    env.ledger().set_timestamp(now + 10 * HOUR);
+    contract.start_maintenance(&owner, &car_id, &String::from_str(&env, "Brakes"));
+
+    // The open entry counts up to now
+    env.ledger().set_timestamp(now + 13 * HOUR);
+    assert_eq!(contract.get_maintenance_downtime(&car_id), 5 * HOUR);
+    assert_eq!(contract.get_maintenance_log(&car_id).len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")]
+pub fn test_rental_car_in_maintenance_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.start_maintenance(&owner, &car_id, &String::from_str(&env, "Oil change"));
+
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+pub fn test_start_maintenance_while_rented_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+
+    contract.start_maintenance(&owner, &car_id, &String::from_str(&env, "Oil change"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+pub fn test_end_maintenance_while_rented_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+
+    contract.end_maintenance(&owner, &car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #31)")]
+pub fn test_end_maintenance_not_in_maintenance_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.end_maintenance(&owner, &car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+pub fn test_start_maintenance_by_stranger_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.start_maintenance(&stranger, &car_id, &String::from_str(&env, "Oil change"));
+}
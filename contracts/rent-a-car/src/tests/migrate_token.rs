@@ -0,0 +1,212 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
+use crate::storage::token::read_token;
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, create_token_contract, get_contract_events}};
+
+#[test]
+pub fn test_migrate_token_successfully() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+    let commission = 100_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, token_issuer) = token;
+    let (new_client, new_admin) = create_token_contract(&env, &token_issuer);
+    token_admin.mint(&renter, &10_000);
+    new_admin.mint(&renter, &10_000);
+
+    contract.set_admin_commission(&commission);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+    contract.return_car(&renter, &car_id);
+
+    contract.payout_owner(&car_id, &token_client.address, &amount);
+    contract.withdraw_admin_commission(&token_client.address, &commission);
+
+    contract.migrate_token(&new_client.address);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let stored_token = env.as_contract(&contract.address, || read_token(&env)).unwrap();
+    assert_eq!(stored_token, new_client.address);
+    assert_eq!(contract.get_allowed_tokens(), vec![&env, new_client.address.clone()]);
+    assert_eq!(contract.get_car_tokens(&car_id), vec![&env, new_client.address.clone()]);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "token_migrated").as_val(),
+                    token_client.address.clone().into_val(&env),
+                    new_client.address.clone().into_val(&env),
+                ],
+                ().into_val(&env)
+            )
+        ]
+    );
+
+    // New rentals are paid in the migrated token
+    contract.rental(&renter, &car_id, &1, &contract.get_quote(&car_id, &1).total, &new_client.address);
+    assert_eq!(new_client.balance(&renter), 10_000 - amount - commission);
+}
+
+#[test]
+pub fn test_migrate_token_rewrites_car_token_lists() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, _, token_issuer) = token;
+    let (other_client, _) = create_token_contract(&env, &token_issuer);
+    let (new_client, new_admin) = create_token_contract(&env, &token_issuer);
+    new_admin.mint(&renter, &10_000);
+
+    contract.allow_token(&other_client.address);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let other_car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_tokens(&car_id, &vec![&env, token_client.address.clone(), other_client.address.clone()]);
+    contract.set_car_tokens(&other_car_id, &vec![&env, other_client.address.clone()]);
+
+    contract.migrate_token(&new_client.address);
+
+    assert_eq!(
+        contract.get_car_tokens(&car_id),
+        vec![&env, new_client.address.clone(), other_client.address.clone()]
+    );
+    assert_eq!(contract.get_car_tokens(&other_car_id), vec![&env, other_client.address.clone()]);
+
+    contract.rental(&renter, &car_id, &1, &1500, &new_client.address);
+    assert_eq!(new_client.balance(&renter), 10_000 - 1500);
+}
+
+#[test]
+pub fn test_migrate_token_after_rentals_are_returned() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, token_issuer) = token;
+    let (new_client, _) = create_token_contract(&env, &token_issuer);
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+    contract.return_car(&renter, &car_id);
+
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+    contract.extend_rental(&renter, &car_id, &1);
+    contract.return_car(&renter, &car_id);
+
+    let settled = contract.get_car_balance(&car_id, &token_client.address).settled;
+    contract.payout_owner(&car_id, &token_client.address, &settled);
+
+    contract.migrate_token(&new_client.address);
+    assert_eq!(contract.get_allowed_tokens(), vec![&env, new_client.address.clone()]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #37)")]
+pub fn test_migrate_token_with_active_rental_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, token_issuer) = token;
+    let (new_client, _) = create_token_contract(&env, &token_issuer);
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+
+    contract.migrate_token(&new_client.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #38)")]
+pub fn test_migrate_token_with_owner_balance_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, token_issuer) = token;
+    let (new_client, _) = create_token_contract(&env, &token_issuer);
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+    contract.return_car(&renter, &car_id);
+
+    contract.migrate_token(&new_client.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #38)")]
+pub fn test_migrate_token_with_admin_balance_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, token_issuer) = token;
+    let (new_client, _) = create_token_contract(&env, &token_issuer);
+    token_admin.mint(&renter, &10_000);
+
+    contract.set_admin_commission(&100);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+    contract.return_car(&renter, &car_id);
+    contract.payout_owner(&car_id, &token_client.address, &amount);
+
+    contract.migrate_token(&new_client.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #38)")]
+pub fn test_migrate_token_with_prepaid_booking_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, token_issuer) = token;
+    let (new_client, _) = create_token_contract(&env, &token_issuer);
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.reserve(&renter, &car_id, &86_400, &(2 * 86_400), &token_client.address);
+
+    contract.migrate_token(&new_client.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+pub fn test_migrate_token_to_admin_address_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.migrate_token(&admin);
+}
@@ -11,5 +11,36 @@ pub mod withdraw_admin_commission;
 pub mod return_car;
 pub mod get_admin_available_to_withdraw;
 pub mod get_owner_available_to_withdraw;
+pub mod get_owner_cars;
+pub mod get_car;
+pub mod list_cars;
+pub mod get_quote;
+pub mod get_rental;
+pub mod set_car_deposit;
+pub mod withhold_deposit;
+pub mod deposit_claim;
+pub mod set_car_late_fee;
+pub mod set_late_grace_period;
+pub mod late_return;
+pub mod update_car_details;
+pub mod reserve;
+pub mod pickup;
+pub mod get_bookings;
+pub mod set_cancellation_policy;
+pub mod cancel_reservation;
+pub mod extend_rental;
+pub mod early_return;
+pub mod maintenance;
+pub mod commission_config;
+pub mod fee_bearer;
+pub mod rental_snapshot;
+pub mod owner_commission;
+pub mod token;
+pub mod migrate_token;
+pub mod rental_paid_by;
+pub mod payout_settings;
+pub mod settle_many;
+pub mod reconcile;
+pub mod statement;
 mod payout_owner;
 mod auth;
\ No newline at end of file
@@ -0,0 +1,239 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
+use crate::storage::structs::commission_config::{CommissionConfig, CommissionTier};
+use crate::storage::structs::owner_volume::OwnerVolume;
+use crate::tests::config::{contract::ContractTest, utils::{bps_config, create_car_details, get_contract_events}};
+
+#[test]
+pub fn test_set_owner_commission_successfully() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let config = bps_config(200, None, None);
+
+    env.mock_all_auths();
+
+    assert_eq!(contract.get_owner_commission(&owner), None);
+
+    contract.set_owner_commission(&owner, &config);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_owner_commission(&owner), Some(config.clone()));
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "owner_commission_set").as_val(),
+                    owner.clone().into_val(&env),
+                ],
+                config.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_owner_commission_overrides_global_commission() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.set_admin_commission(&500);
+    contract.set_owner_commission(&owner, &bps_config(200, None, None));
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let other_car_id = contract.add_car(&other_owner, &1500_i128, &create_car_details(&env));
+
+    assert_eq!(contract.get_quote(&car_id, &2).admin_commission, 60);
+    assert_eq!(contract.get_quote(&other_car_id, &2).admin_commission, 500);
+}
+
+#[test]
+pub fn test_remove_owner_commission_restores_global_commission() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.set_admin_commission(&500);
+    contract.set_owner_commission(&owner, &bps_config(200, None, None));
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract.remove_owner_commission(&owner);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_owner_commission(&owner), None);
+    assert_eq!(contract.get_quote(&car_id, &2).admin_commission, 500);
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "owner_commission_removed").as_val(),
+                    owner.clone().into_val(&env),
+                ],
+                ().into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")]
+pub fn test_set_owner_commission_with_invalid_config_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.set_owner_commission(&owner, &bps_config(20_000, None, None));
+}
+
+#[test]
+pub fn test_set_commission_tiers_successfully() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let tiers = vec![
+        &env,
+        CommissionTier { min_rentals: 2, min_revenue: 0, config: bps_config(500, None, None) },
+        CommissionTier { min_rentals: 5, min_revenue: 10_000, config: bps_config(200, None, None) },
+    ];
+
+    env.mock_all_auths();
+
+    contract.set_commission_tiers(&tiers);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_commission_tiers(), tiers.clone());
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![&env, *Symbol::new(&env, "commission_tiers_set").as_val()],
+                tiers.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")]
+pub fn test_set_commission_tiers_out_of_order_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.set_commission_tiers(&vec![
+        &env,
+        CommissionTier { min_rentals: 5, min_revenue: 0, config: bps_config(200, None, None) },
+        CommissionTier { min_rentals: 2, min_revenue: 0, config: bps_config(500, None, None) },
+    ]);
+}
+
+#[test]
+pub fn test_commission_drops_after_volume_tier_is_reached() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &100_000);
+
+    contract.set_commission_config(&bps_config(1_000, None, None));
+    contract.set_commission_tiers(&vec![
+        &env,
+        CommissionTier { min_rentals: 2, min_revenue: 0, config: bps_config(500, None, None) },
+        CommissionTier { min_rentals: 5, min_revenue: 10_000, config: bps_config(100, None, None) },
+    ]);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    assert_eq!(contract.get_quote(&car_id, &2).admin_commission, 300);
+
+    for _ in 0..2 {
+        contract.rental(&renter, &car_id, &2, &contract.get_quote(&car_id, &2).total, &token_client.address);
+        contract.return_car(&renter, &car_id);
+    }
+
+    assert_eq!(
+        contract.get_owner_volume(&owner),
+        OwnerVolume { completed_rentals: 2, revenue: 6000 }
+    );
+    assert_eq!(contract.get_quote(&car_id, &2).admin_commission, 150);
+
+    // Only the revenue threshold of the next tier is crossed, which is enough to reach it
+    contract.rental(&renter, &car_id, &2, &contract.get_quote(&car_id, &2).total, &token_client.address);
+    contract.return_car(&renter, &car_id);
+    contract.rental(&renter, &car_id, &2, &contract.get_quote(&car_id, &2).total, &token_client.address);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(contract.get_quote(&car_id, &2).admin_commission, 30);
+}
+
+#[test]
+pub fn test_commission_tier_reached_by_rentals_alone() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &100_000);
+
+    contract.set_commission_config(&bps_config(1_000, None, None));
+    contract.set_commission_tiers(&vec![
+        &env,
+        CommissionTier { min_rentals: 2, min_revenue: 1_000_000, config: bps_config(500, None, None) },
+    ]);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    for _ in 0..2 {
+        contract.rental(&renter, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+        contract.return_car(&renter, &car_id);
+    }
+
+    assert_eq!(
+        contract.get_owner_volume(&owner),
+        OwnerVolume { completed_rentals: 2, revenue: 3000 }
+    );
+    assert_eq!(contract.get_quote(&car_id, &2).admin_commission, 150);
+}
+
+#[test]
+pub fn test_owner_commission_takes_priority_over_tiers() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &100_000);
+
+    contract.set_commission_tiers(&vec![
+        &env,
+        CommissionTier { min_rentals: 0, min_revenue: 0, config: bps_config(500, None, None) },
+    ]);
+    contract.set_owner_commission(&owner, &CommissionConfig::flat(10));
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    assert_eq!(contract.get_quote(&car_id, &2).admin_commission, 10);
+}
@@ -1,8 +1,10 @@
-﻿use crate::{
+﻿use crate::tests::config::utils::create_car_details;
+use crate::{
     storage::{car::read_car, contract_balance::read_contract_balance, types::car_status::CarStatus},
     tests::config::{contract::ContractTest, utils::get_contract_events},
 };
 use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
+use crate::storage::structs::earnings_statement::EarningsStatement;
 
 #[test]
 pub fn test_payout_owner_successfully() {
@@ -20,48 +22,90 @@ pub fn test_payout_owner_successfully() {
     let amount = 4500_i128;
 
     env.mock_all_auths();
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
 
     // Return the car before withdrawing
-    contract.return_car(&renter, &owner);
+    contract.return_car(&renter, &car_id);
 
-    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
     assert_eq!(contract_balance, amount);
 
-    contract.payout_owner(&owner, &amount);
+    contract.payout_owner(&car_id, &token_client.address, &amount);
     let contract_events = get_contract_events(&env, &contract.address);
 
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
-    assert_eq!(car.available_to_withdraw, 0);
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, 0);
 
-    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
     assert_eq!(contract_balance, 0);
     assert_eq!(
         contract_events,
         vec![
             &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "statement_entry").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (
+                    token_client.address.clone(),
+                    EarningsStatement { rental_count: 0, total_earned: 0, total_refunded: 0, total_withdrawn: amount, fees_paid: 0 },
+                ).into_val(&env)
+            ),
             (
                 contract.address.clone(),
                 vec![
                     &env,
                     *Symbol::new(&env, "payout").as_val(),
                     owner.clone().into_val(&env),
+                    car_id.into_val(&env),
                 ],
-                amount.into_val(&env)
+                (token_client.address.clone(), amount).into_val(&env)
             )
         ]
     );
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #13)")]
-pub fn test_payout_owner_when_car_is_rented_fails() {
+#[should_panic(expected = "Error(Contract, #8)")]
+pub fn test_payout_owner_pending_rental_fails() {
+    let ContractTest {
+        env,
+        contract,
+        token,
+        ..
+    } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+
+    env.mock_all_auths();
+    let (token_client, token_admin, _) = token;
+
+    let amount_mint = 10_000_i128;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
+
+    // The current rental is still pending and cannot be withdrawn
+    contract.payout_owner(&car_id, &token_client.address, &amount);
+}
+
+#[test]
+pub fn test_payout_owner_settled_while_car_is_rented() {
     let ContractTest {
         env,
         contract,
@@ -76,16 +120,24 @@ pub fn test_payout_owner_when_car_is_rented_fails() {
     let amount = 4500_i128;
 
     env.mock_all_auths();
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
+    contract.return_car(&renter, &car_id);
+    contract.rental(&renter, &car_id, &1, &price_per_day, &token_client.address);
+
+    // Earnings from the first rental are settled even though the car is out again
+    contract.payout_owner(&car_id, &token_client.address, &amount);
+
+    assert_eq!(token_client.balance(&owner), amount);
 
-    // Try to withdraw while car is still rented (should fail)
-    contract.payout_owner(&owner, &amount);
+    let balance = contract.get_owner_available_to_withdraw(&owner, &token_client.address);
+    assert_eq!(balance.settled, 0);
+    assert_eq!(balance.pending, price_per_day);
 }
 
 #[test]
@@ -104,29 +156,29 @@ pub fn test_payout_owner_after_return_car_successfully() {
     let amount = 4500_i128;
 
     env.mock_all_auths();
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
 
     // Verify car is rented
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Rented);
 
     // Try to withdraw while rented (should fail)
     // This is tested separately, so we'll just return the car
-    contract.return_car(&renter, &owner);
+    contract.return_car(&renter, &car_id);
 
     // Verify car is now available
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Available);
 
     // Now withdrawal should succeed
-    contract.payout_owner(&owner, &amount);
+    contract.payout_owner(&car_id, &token_client.address, &amount);
 
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
-    assert_eq!(car.available_to_withdraw, 0);
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, 0);
 }
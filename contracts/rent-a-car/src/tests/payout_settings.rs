@@ -0,0 +1,179 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
+use crate::storage::contract_balance::read_contract_balance;
+use crate::storage::structs::payout_settings::PayoutSettings;
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, get_contract_events}};
+use crate::storage::structs::earnings_statement::EarningsStatement;
+
+#[test]
+pub fn test_payout_settings_default_to_owner() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    assert_eq!(
+        contract.get_payout_settings(&owner),
+        PayoutSettings { destination: owner.clone(), auto_payout: false }
+    );
+}
+
+#[test]
+pub fn test_payout_owner_goes_to_destination() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let amount = 1500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.set_payout_settings(&owner, &treasury, &false);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &amount, &token_client.address);
+    contract.return_car(&renter, &car_id);
+
+    // Without auto-payout the earnings wait for a manual payout
+    assert_eq!(contract.get_car_balance(&car_id, &token_client.address).settled, amount);
+
+    contract.payout_owner(&car_id, &token_client.address, &amount);
+
+    assert_eq!(token_client.balance(&treasury), amount);
+    assert_eq!(token_client.balance(&owner), 0);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "payout_settings_set").as_val(),
+                    owner.clone().into_val(&env),
+                ],
+                (treasury.clone(), false).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_auto_payout_on_return() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let amount = 3000_i128;
+    let commission = 100_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.set_admin_commission(&commission);
+    contract.set_payout_settings(&owner, &treasury, &true);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &2, &contract.get_quote(&car_id, &2).total, &token_client.address);
+    contract.return_car(&renter, &car_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(token_client.balance(&treasury), amount);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.pending, 0);
+    assert_eq!(balance.settled, 0);
+
+    // Only the admin commission is left in the contract
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, commission);
+    assert_eq!(token_client.balance(&contract.address), commission);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "statement_entry").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (
+                    token_client.address.clone(),
+                    EarningsStatement { rental_count: 0, total_earned: 0, total_refunded: 0, total_withdrawn: amount, fees_paid: 0 },
+                ).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_returned").as_val(),
+                    renter.clone().into_val(&env),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (token_client.address.clone(), env.ledger().timestamp(), 0_i128, 0_i128).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "auto_payout").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (treasury.clone(), token_client.address.clone(), amount).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_auto_payout_includes_withheld_deposit() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+    let deposit = 500_i128;
+    let withheld = 200_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.set_payout_settings(&owner, &owner, &true);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.rental(&renter, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+    contract.withhold_deposit(&car_id, &withheld, &1);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(token_client.balance(&owner), amount + withheld);
+    assert_eq!(token_client.balance(&renter), 10_000 - amount - withheld);
+    assert_eq!(token_client.balance(&contract.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #39)")]
+pub fn test_set_payout_destination_to_contract_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.set_payout_settings(&owner, &contract.address, &true);
+}
@@ -0,0 +1,197 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::storage::{car::read_car, contract_balance::read_contract_balance, escrow::read_escrow_balance, rental::read_rental};
+use crate::storage::types::car_status::CarStatus;
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, get_contract_events}};
+use crate::storage::structs::earnings_statement::EarningsStatement;
+
+const DAY: u64 = 86_400;
+
+#[test]
+pub fn test_pickup_turns_booking_into_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let commission = 100_i128;
+    let deposit = 500_i128;
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+    let start_ts = now + DAY;
+    let end_ts = start_ts + 2 * DAY;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    contract.set_admin_commission(&commission);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+
+    let booking_id = contract.reserve(&renter, &car_id, &start_ts, &end_ts, &token_client.address);
+
+    let pickup_ts = start_ts + 3_600;
+    env.ledger().set_timestamp(pickup_ts);
+
+    contract.pickup(&renter, &car_id, &booking_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let amount = 2 * price_per_day;
+    assert_eq!(token_client.balance(&renter), amount_mint - amount - commission - deposit);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(car.car_status, CarStatus::Rented);
+    assert_eq!(balance.pending, amount);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), commission);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, amount + commission);
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, deposit);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, car_id)).unwrap();
+    assert_eq!(rental.renter, renter);
+    assert_eq!(rental.total_days_to_rent, 2);
+    assert_eq!(rental.amount, amount);
+    assert_eq!(rental.start_ts, pickup_ts);
+    assert_eq!(rental.due_ts, end_ts);
+    assert_eq!(rental.deposit, deposit);
+
+    assert_eq!(contract.get_bookings(&car_id, &now, &(now + 10 * DAY)).len(), 0);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "statement_entry").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (
+                    token_client.address.clone(),
+                    EarningsStatement { rental_count: 1, total_earned: amount, total_refunded: 0, total_withdrawn: 0, fees_paid: 0 },
+                ).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "picked_up").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (booking_id, end_ts).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "deposit_held").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                deposit.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+pub fn test_pickup_before_start_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &(now + DAY), &(now + 2 * DAY), &token_client.address);
+
+    contract.pickup(&renter, &car_id, &booking_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+pub fn test_pickup_after_end_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &(now + DAY), &(now + 2 * DAY), &token_client.address);
+
+    env.ledger().set_timestamp(now + 2 * DAY);
+    contract.pickup(&renter, &car_id, &booking_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+pub fn test_pickup_by_other_renter_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let other_renter = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &(now + DAY), &(now + 2 * DAY), &token_client.address);
+
+    env.ledger().set_timestamp(now + DAY);
+    contract.pickup(&other_renter, &car_id, &booking_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+pub fn test_pickup_while_car_is_still_rented_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter_1 = Address::generate(&env);
+    let renter_2 = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter_1, &10_000_i128);
+    token_admin.mint(&renter_2, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter_1, &car_id, &1, &1500, &token_client.address);
+    let booking_id = contract.reserve(&renter_2, &car_id, &(now + DAY), &(now + 2 * DAY), &token_client.address);
+
+    // The walk-in renter is late and the car is not back yet
+    env.ledger().set_timestamp(now + DAY + 60);
+    contract.pickup(&renter_2, &car_id, &booking_id);
+}
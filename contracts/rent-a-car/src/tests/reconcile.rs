@@ -0,0 +1,145 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
+use crate::storage::structs::reconciliation::Reconciliation;
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, get_contract_events}};
+
+#[test]
+pub fn test_reconcile_matches_books_after_rentals() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let other_renter = Address::generate(&env);
+    let amount = 1500_i128;
+    let commission = 100_i128;
+    let deposit = 500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+    token_admin.mint(&other_renter, &10_000);
+
+    contract.set_admin_commission(&commission);
+    let returned_car = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let rented_car = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&rented_car, &deposit);
+
+    contract.rental(&renter, &returned_car, &1, &contract.get_quote(&returned_car, &1).total, &token_client.address);
+    contract.return_car(&renter, &returned_car);
+    contract.rental(&other_renter, &rented_car, &1, &contract.get_quote(&rented_car, &1).total, &token_client.address);
+
+    assert_eq!(
+        contract.reconcile(&token_client.address),
+        Reconciliation {
+            book_balance: 2 * (amount + commission),
+            actual_balance: 2 * (amount + commission) + deposit,
+            owner_liabilities: 2 * amount,
+            admin_liabilities: 2 * commission,
+            escrow_liabilities: deposit,
+            surplus: 0,
+        }
+    );
+}
+
+#[test]
+pub fn test_reconcile_owner_liabilities_follow_payouts_and_retired_cars() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let paid_car = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let retired_car = contract.add_car(&other_owner, &1500_i128, &create_car_details(&env));
+
+    for car_id in [paid_car, retired_car] {
+        contract.rental(&renter, &car_id, &1, &amount, &token_client.address);
+        contract.return_car(&renter, &car_id);
+    }
+
+    contract.payout_owner(&paid_car, &token_client.address, &500);
+    contract.remove_car(&retired_car);
+
+    let reconciliation = contract.reconcile(&token_client.address);
+    assert_eq!(reconciliation.owner_liabilities, 2 * amount - 500);
+    assert_eq!(reconciliation.book_balance, 2 * amount - 500);
+    assert_eq!(reconciliation.surplus, 0);
+}
+
+#[test]
+pub fn test_sweep_surplus_moves_only_the_excess() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let amount = 1500_i128;
+    let stray = 700_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &amount, &token_client.address);
+    contract.return_car(&renter, &car_id);
+
+    // Tokens sent straight to the contract are not tracked by the books
+    token_admin.mint(&contract.address, &stray);
+    assert_eq!(contract.reconcile(&token_client.address).surplus, stray);
+
+    let swept = contract.sweep_surplus(&token_client.address, &treasury);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(swept, stray);
+    assert_eq!(token_client.balance(&treasury), stray);
+    assert_eq!(token_client.balance(&contract.address), amount);
+    assert_eq!(contract.reconcile(&token_client.address).surplus, 0);
+
+    // The owner can still withdraw everything they earned
+    contract.payout_owner(&car_id, &token_client.address, &amount);
+    assert_eq!(token_client.balance(&owner), amount);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "surplus_swept").as_val(),
+                    token_client.address.clone().into_val(&env),
+                    treasury.clone().into_val(&env),
+                ],
+                stray.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #40)")]
+pub fn test_sweep_surplus_without_surplus_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+
+    contract.sweep_surplus(&token_client.address, &Address::generate(&env));
+}
@@ -1,8 +1,12 @@
-﻿use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address, Symbol, vec};
-use crate::{storage::car::has_car, tests::config::{contract::ContractTest, utils::get_contract_events}};
+﻿use crate::tests::config::utils::{create_car_details, create_token_contract};
+use soroban_sdk::{testutils::{Address as _, Ledger}, IntoVal, Address, Symbol, String, vec, Vec};
+use crate::storage::types::car_status::CarStatus;
+use crate::{storage::car::{car_index_chunk, has_car, read_car_index, read_owner_cars}, tests::config::{contract::ContractTest, utils::get_contract_events}};
+
+const DAY: u64 = 86_400;
 
 #[test]
-pub fn test_remove_car_deletes_from_storage() {
+pub fn test_remove_car_retires_car() {
     let ContractTest { env, contract, .. } = ContractTest::setup();
 
     env.mock_all_auths();
@@ -10,17 +14,111 @@ pub fn test_remove_car_deletes_from_storage() {
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+
+    contract.remove_car(&car_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
     assert!(env.as_contract(&contract.address, || {
-        has_car(&env, &owner)
+        has_car(&env, car_id)
     }));
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Retired);
+    assert_eq!(env.as_contract(&contract.address, || {
+        read_owner_cars(&env, &owner)
+    }), vec![&env, car_id]);
+    assert!(env.as_contract(&contract.address, || {
+        read_car_index(&env, car_index_chunk(car_id))
+    }).is_empty());
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_retired").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                Vec::<(Address, i128)>::new(&env).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_owner_can_withdraw_after_car_is_retired() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 3000_i128;
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
 
-    contract.remove_car(&owner);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &2, &amount, &token_client.address);
+    contract.return_car(&renter, &car_id);
+
+    contract.remove_car(&car_id);
     let contract_events = get_contract_events(&env, &contract.address);
 
-    assert!(!env.as_contract(&contract.address, || {
-        has_car(&env, &owner)
-    }));
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_retired").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                vec![&env, (token_client.address.clone(), amount)].into_val(&env)
+            )
+        ]
+    );
+
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token_client.address).settled, amount);
+
+    contract.payout_owner(&car_id, &token_client.address, &amount);
+
+    assert_eq!(token_client.balance(&owner), amount);
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, 0);
+}
+
+#[test]
+pub fn test_remove_car_reports_balance_in_disallowed_token() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 3000_i128;
+
+    let (token_client, _, token_issuer) = token;
+    let (second_client, second_admin) = create_token_contract(&env, &token_issuer);
+    second_admin.mint(&renter, &10_000_i128);
+
+    contract.allow_token(&second_client.address);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_tokens(&car_id, &vec![&env, token_client.address.clone(), second_client.address.clone()]);
+    contract.rental(&renter, &car_id, &2, &amount, &second_client.address);
+    contract.return_car(&renter, &car_id);
+
+    // The earnings stay withdrawable after the token is no longer accepted for new rentals
+    contract.disallow_token(&second_client.address);
+
+    contract.remove_car(&car_id);
+    let contract_events = get_contract_events(&env, &contract.address);
 
     assert_eq!(
         contract_events,
@@ -30,23 +128,112 @@ pub fn test_remove_car_deletes_from_storage() {
                 contract.address.clone(),
                 vec![
                     &env,
-                    *Symbol::new(&env, "car_removed").as_val(),
+                    *Symbol::new(&env, "car_retired").as_val(),
                     owner.clone().into_val(&env),
+                    car_id.into_val(&env),
                 ],
-                ().into_val(&env)
+                vec![&env, (second_client.address.clone(), amount)].into_val(&env)
             )
         ]
     );
 }
 
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+pub fn test_remove_rented_car_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+
+    contract.remove_car(&car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+pub fn test_remove_car_with_reservation_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.reserve(&renter, &car_id, &(now + DAY), &(now + 2 * DAY), &token_client.address);
+
+    contract.remove_car(&car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")]
+pub fn test_remove_car_in_maintenance_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.start_maintenance(&owner, &car_id, &String::from_str(&env, "Oil change"));
+
+    contract.remove_car(&car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_rental_retired_car_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.remove_car(&car_id);
+
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_remove_car_twice_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.remove_car(&car_id);
+    contract.remove_car(&car_id);
+}
+
 
 #[test]
 #[should_panic(expected = "Error(Contract, #2)")]
 pub fn test_remove_car_not_found_fails() {
     let ContractTest { env, contract, .. } = ContractTest::setup();
-    let owner = Address::generate(&env);
+    let car_id = 0_u32;
 
     env.mock_all_auths();
 
-    contract.remove_car(&owner);
-}
\ No newline at end of file
+    contract.remove_car(&car_id);
+}
@@ -1,6 +1,8 @@
-﻿use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
-use crate::{storage::{car::read_car, contract_balance::read_contract_balance, rental::read_rental, types::car_status::CarStatus}, tests::config::contract::ContractTest};
+﻿use crate::tests::config::utils::create_car_details;
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
+use crate::{storage::{car::read_car, contract_balance::read_contract_balance, escrow::read_escrow_balance, rental::read_rental, types::car_status::CarStatus}, tests::config::contract::ContractTest};
 use crate::tests::config::utils::get_contract_events;
+use crate::storage::structs::earnings_statement::EarningsStatement;
 
 #[test]
 pub fn test_rental_car_successfully() {
@@ -14,34 +16,48 @@ pub fn test_rental_car_successfully() {
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint( &renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
 
-    let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
     assert_eq!(initial_contract_balance, 0);
 
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
     let contract_events = get_contract_events(&env, &contract.address);
 
-    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
     // When commission is 0 (default), total amount equals rental amount
     assert_eq!(updated_contract_balance, amount);
 
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
     assert_eq!(car.car_status, CarStatus::Rented);
-    assert_eq!(car.available_to_withdraw, amount);
+    assert_eq!(balance.pending, amount);
 
-    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner)).unwrap();
+    let rental = env.as_contract(&contract.address, || read_rental(&env, car_id)).unwrap();
     assert_eq!(rental.total_days_to_rent, total_days);
     assert_eq!(rental.amount, amount);
     assert_eq!(
         contract_events,
         vec![
             &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "statement_entry").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (
+                    token_client.address.clone(),
+                    EarningsStatement { rental_count: 1, total_earned: amount, total_refunded: 0, total_withdrawn: 0, fees_paid: 0 },
+                ).into_val(&env)
+            ),
             (
                 contract.address.clone(),
                 vec![
@@ -49,8 +65,9 @@ pub fn test_rental_car_successfully() {
                     *Symbol::new(&env, "rented").as_val(),
                     renter.clone().into_val(&env),
                     owner.clone().into_val(&env),
+                    car_id.into_val(&env),
                 ],
-                (total_days, amount).into_val(&env)
+                (token_client.address.clone(), total_days, amount, amount, 0_i128).into_val(&env)
             )
         ]
     );
@@ -70,31 +87,32 @@ pub fn test_rental_with_admin_commission() {
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
     
     // Set admin commission
     contract.set_admin_commission(&commission);
 
-    let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
     assert_eq!(initial_contract_balance, 0);
 
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &contract.get_quote(&car_id, &total_days).total, &token_client.address);
 
-    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
     // Contract balance should include both amount and commission
     assert_eq!(updated_contract_balance, expected_total_amount);
 
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
     assert_eq!(car.car_status, CarStatus::Rented);
     // Owner should receive the full rental amount (commission is added to deposit, not deducted)
-    assert_eq!(car.available_to_withdraw, amount);
+    assert_eq!(balance.pending, amount);
 
-    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner)).unwrap();
+    let rental = env.as_contract(&contract.address, || read_rental(&env, car_id)).unwrap();
     assert_eq!(rental.total_days_to_rent, total_days);
     assert_eq!(rental.amount, amount);
 }
@@ -111,25 +129,25 @@ pub fn test_rental_with_zero_commission() {
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
     
     // Set commission to zero (default behavior)
     contract.set_admin_commission(&0_i128);
 
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
 
-    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
     // When commission is 0, total amount equals rental amount
     assert_eq!(updated_contract_balance, amount);
 
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
     // Owner should receive full amount when commission is 0
-    assert_eq!(car.available_to_withdraw, amount);
+    assert_eq!(balance.pending, amount);
 }
 
 #[test]
@@ -145,26 +163,174 @@ pub fn test_rental_with_commission_added_to_deposit() {
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 20_000_i128; // Enough to cover amount + commission
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
     
     // Set commission (even if greater than amount, it just gets added to deposit)
     contract.set_admin_commission(&commission);
 
-    let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
     assert_eq!(initial_contract_balance, 0);
 
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &contract.get_quote(&car_id, &total_days).total, &token_client.address);
 
-    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
     // Contract balance should include amount + commission
     assert_eq!(updated_contract_balance, amount + commission);
 
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
     // Owner receives full rental amount
-    assert_eq!(car.available_to_withdraw, amount);
-}
\ No newline at end of file
+    assert_eq!(balance.pending, amount);
+}
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")]
+pub fn test_rental_with_underpayment_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 1_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")]
+pub fn test_rental_amount_below_quote_total_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    contract.set_admin_commission(&100);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &500);
+
+    // The base price alone no longer covers what the contract would pull
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+}
+
+#[test]
+pub fn test_rental_charges_quoted_price_when_overpaying() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let expected_price = price_per_day * total_days as i128;
+    let amount = 9000_i128;
+    let amount_mint = 10_000_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - expected_price);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, car_id)).unwrap();
+    assert_eq!(rental.amount, expected_price);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.pending, expected_price);
+}
+
+#[test]
+pub fn test_rental_holds_deposit_in_escrow() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+    let deposit = 1000_i128;
+    let amount_mint = 10_000_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+
+    contract.rental(&renter, &car_id, &total_days, &contract.get_quote(&car_id, &total_days).total, &token_client.address);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - amount - deposit);
+
+    // Deposit is kept out of the earnings balance
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, amount);
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, deposit);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, car_id)).unwrap();
+    assert_eq!(rental.deposit, deposit);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "statement_entry").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (
+                    token_client.address.clone(),
+                    EarningsStatement { rental_count: 1, total_earned: amount, total_refunded: 0, total_withdrawn: 0, fees_paid: 0 },
+                ).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "rented").as_val(),
+                    renter.clone().into_val(&env),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (token_client.address.clone(), total_days, amount, amount, 0_i128).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "deposit_held").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                deposit.into_val(&env)
+            )
+        ]
+    );
+}
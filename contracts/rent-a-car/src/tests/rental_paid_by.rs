@@ -0,0 +1,273 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::storage::rental::read_rental;
+use crate::storage::structs::early_return_policy::EarlyReturnPolicy;
+use crate::storage::types::payer::Payer;
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, get_contract_events}};
+use crate::storage::structs::earnings_statement::EarningsStatement;
+
+#[test]
+pub fn test_rental_paid_by_employer() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+    let amount = 3000_i128;
+    let commission = 100_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&employer, &10_000);
+
+    contract.set_admin_commission(&commission);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental_paid_by(&renter, &car_id, &2, &contract.get_quote(&car_id, &2).total, &token_client.address, &Payer::Auth(employer.clone()));
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(token_client.balance(&employer), 10_000 - amount - commission);
+    assert_eq!(token_client.balance(&renter), 0);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, car_id)).unwrap();
+    assert_eq!(rental.renter, renter);
+    assert_eq!(rental.payer, Payer::Auth(employer.clone()));
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "statement_entry").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (
+                    token_client.address.clone(),
+                    EarningsStatement { rental_count: 1, total_earned: amount, total_refunded: 0, total_withdrawn: 0, fees_paid: 0 },
+                ).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "rented").as_val(),
+                    renter.clone().into_val(&env),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (token_client.address.clone(), 2_u32, amount, amount, commission).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "rental_paid_by").as_val(),
+                    employer.clone().into_val(&env),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (amount + commission).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_rental_paid_through_allowance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+    let amount = 1500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&employer, &10_000);
+    token_client.approve(&employer, &contract.address, &5000, &(env.ledger().sequence() + 100));
+    contract.set_driver_approval(&employer, &renter, &true);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental_paid_by(&renter, &car_id, &1, &amount, &token_client.address, &Payer::Allowance(employer.clone()));
+
+    assert_eq!(token_client.balance(&employer), 10_000 - amount);
+    assert_eq!(token_client.allowance(&employer, &contract.address), 5000 - amount);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+pub fn test_rental_paid_through_allowance_too_low_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&employer, &10_000);
+    token_client.approve(&employer, &contract.address, &1000, &(env.ledger().sequence() + 100));
+    contract.set_driver_approval(&employer, &renter, &true);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental_paid_by(&renter, &car_id, &1, &1500, &token_client.address, &Payer::Allowance(employer));
+}
+
+#[test]
+pub fn test_set_driver_approval_successfully() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    assert!(!contract.is_driver_approved(&employer, &renter));
+
+    contract.set_driver_approval(&employer, &renter, &true);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert!(contract.is_driver_approved(&employer, &renter));
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "driver_approval_set").as_val(),
+                    employer.clone().into_val(&env),
+                    renter.clone().into_val(&env),
+                ],
+                true.into_val(&env)
+            )
+        ]
+    );
+
+    contract.set_driver_approval(&employer, &renter, &false);
+    assert!(!contract.is_driver_approved(&employer, &renter));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #43)")]
+pub fn test_rental_paid_through_allowance_after_revoked_approval_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&employer, &10_000);
+    token_client.approve(&employer, &contract.address, &5000, &(env.ledger().sequence() + 100));
+    contract.set_driver_approval(&employer, &renter, &true);
+    contract.set_driver_approval(&employer, &renter, &false);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental_paid_by(&renter, &car_id, &1, &1500, &token_client.address, &Payer::Allowance(employer));
+}
+
+#[test]
+pub fn test_refunds_go_back_to_payer() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+    let deposit = 500_i128;
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&employer, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.set_car_early_return_policy(&car_id, &EarlyReturnPolicy { enabled: true, fee: 0 });
+    let total = contract.get_quote(&car_id, &3).total;
+    contract.rental_paid_by(&renter, &car_id, &3, &total, &token_client.address, &Payer::Auth(employer.clone()));
+
+    // Back after a day and a bit: one full day is unused
+    env.ledger().set_timestamp(now + 86_400 + 3_600);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(token_client.balance(&employer), 10_000 - total + 1500 + deposit);
+    assert_eq!(token_client.balance(&renter), 0);
+}
+
+#[test]
+pub fn test_extension_is_charged_to_payer() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&employer, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental_paid_by(&renter, &car_id, &1, &1500, &token_client.address, &Payer::Auth(employer.clone()));
+    contract.extend_rental(&renter, &car_id, &1);
+
+    assert_eq!(token_client.balance(&employer), 10_000 - 3000);
+    assert_eq!(token_client.balance(&renter), 0);
+}
+
+#[test]
+pub fn test_extension_is_charged_to_payer_allowance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&employer, &10_000);
+    token_client.approve(&employer, &contract.address, &5000, &(env.ledger().sequence() + 100));
+    contract.set_driver_approval(&employer, &renter, &true);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental_paid_by(&renter, &car_id, &1, &1500, &token_client.address, &Payer::Allowance(employer.clone()));
+    contract.extend_rental(&renter, &car_id, &1);
+
+    assert_eq!(token_client.balance(&employer), 10_000 - 3000);
+    assert_eq!(token_client.allowance(&employer, &contract.address), 5000 - 3000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #43)")]
+pub fn test_extension_after_revoked_approval_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let employer = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&employer, &10_000);
+    token_client.approve(&employer, &contract.address, &5000, &(env.ledger().sequence() + 100));
+    contract.set_driver_approval(&employer, &renter, &true);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental_paid_by(&renter, &car_id, &1, &1500, &token_client.address, &Payer::Allowance(employer.clone()));
+
+    contract.set_driver_approval(&employer, &renter, &false);
+    contract.extend_rental(&renter, &car_id, &1);
+}
@@ -0,0 +1,137 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address};
+use crate::storage::{rental::read_rental, token::write_token};
+use crate::storage::structs::cancellation_policy::{CancellationPolicy, CancellationTier};
+use crate::storage::types::fee_bearer::FeeBearer;
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, create_token_contract}};
+
+const DAY: u64 = 86_400;
+
+#[test]
+pub fn test_rental_snapshots_commission_fee_bearer_and_token() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission = 300_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.set_admin_commission(&commission);
+    contract.set_owner_fee_bearer(&owner, &FeeBearer::Owner);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &2, &3000, &token_client.address);
+
+    contract.set_admin_commission(&0);
+    contract.set_owner_fee_bearer(&owner, &FeeBearer::Renter);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, car_id)).unwrap();
+    assert_eq!(rental.admin_commission, commission);
+    assert_eq!(rental.fee_bearer, FeeBearer::Owner);
+    assert_eq!(rental.token, token_client.address);
+}
+
+#[test]
+pub fn test_cancel_reservation_uses_snapshot_after_config_change() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission = 200_i128;
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+
+    let policy = CancellationPolicy {
+        tiers: vec![&env, CancellationTier { min_hours_before: 0, refund_bps: 10_000 }],
+        refund_commission: true,
+    };
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, token_issuer) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    contract.set_admin_commission(&commission);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_cancellation_policy(&car_id, &policy);
+    let booking_id = contract.reserve(&renter, &car_id, &(now + 3 * DAY), &(now + 5 * DAY), &token_client.address);
+
+    // Neither a new commission, a new fee bearer nor a new token change what the booking owes back
+    env.ledger().set_timestamp(now + DAY);
+    let (other_token, _) = create_token_contract(&env, &token_issuer);
+    contract.set_admin_commission(&1_000);
+    contract.set_fee_bearer(&FeeBearer::Owner);
+    env.as_contract(&contract.address, || write_token(&env, &other_token.address));
+
+    contract.cancel_reservation(&renter, &car_id, &booking_id);
+
+    assert_eq!(token_client.balance(&renter), amount_mint);
+    assert_eq!(other_token.balance(&renter), 0);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), 0);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, 0);
+}
+
+#[test]
+pub fn test_return_car_refunds_in_rental_token() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let deposit = 1000_i128;
+    let amount_mint = 10_000_i128;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000_000);
+
+    let (token_client, token_admin, token_issuer) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.rental(&renter, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+
+    let (other_token, _) = create_token_contract(&env, &token_issuer);
+    env.as_contract(&contract.address, || write_token(&env, &other_token.address));
+
+    env.ledger().set_timestamp(1_000_000 + DAY);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - 1500);
+}
+
+#[test]
+pub fn test_pickup_uses_reservation_token() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let now = 1_000_000_u64;
+    let start_ts = now + DAY;
+    let end_ts = start_ts + DAY;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, token_issuer) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &500);
+    let booking_id = contract.reserve(&renter, &car_id, &start_ts, &end_ts, &token_client.address);
+
+    let (other_token, _) = create_token_contract(&env, &token_issuer);
+    env.as_contract(&contract.address, || write_token(&env, &other_token.address));
+
+    env.ledger().set_timestamp(start_ts);
+    contract.pickup(&renter, &car_id, &booking_id);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - 1500 - 500);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, car_id)).unwrap();
+    assert_eq!(rental.token, token_client.address);
+}
@@ -0,0 +1,404 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, String, Symbol};
+use crate::storage::{car::read_car, contract_balance::read_contract_balance, escrow::read_escrow_balance};
+use crate::storage::types::car_status::CarStatus;
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, get_contract_events}};
+
+const DAY: u64 = 86_400;
+
+#[test]
+pub fn test_reserve_holds_prepayment_in_escrow() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let commission = 100_i128;
+    let amount_mint = 10_000_i128;
+    let now = 1_000_000_u64;
+    let start_ts = now + DAY;
+    let end_ts = start_ts + 2 * DAY;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    contract.set_admin_commission(&commission);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+
+    let booking_id = contract.reserve(&renter, &car_id, &start_ts, &end_ts, &token_client.address);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let prepaid = 2 * price_per_day + commission;
+    assert_eq!(booking_id, 0);
+    assert_eq!(token_client.balance(&renter), amount_mint - prepaid);
+    assert_eq!(token_client.balance(&contract.address), prepaid);
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, prepaid);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, 0);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(car.car_status, CarStatus::Available);
+    assert_eq!(balance.settled, 0);
+
+    let bookings = contract.get_bookings(&car_id, &start_ts, &end_ts);
+    assert_eq!(bookings.len(), 1);
+
+    let booking = bookings.get(0).unwrap();
+    assert_eq!(booking.renter, renter);
+    assert_eq!(booking.total_days, 2);
+    assert_eq!(booking.amount, 2 * price_per_day);
+    assert_eq!(booking.admin_commission, commission);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "reserved").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (booking_id, token_client.address.clone(), start_ts, end_ts).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_reserve_charges_started_days_in_full() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.reserve(&renter, &car_id, &now, &(now + DAY + 1), &token_client.address);
+
+    let booking = contract.get_bookings(&car_id, &now, &(now + DAY)).get(0).unwrap();
+    assert_eq!(booking.total_days, 2);
+    assert_eq!(booking.amount, 3000);
+}
+
+#[test]
+pub fn test_reserve_back_to_back_bookings() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter_1 = Address::generate(&env);
+    let renter_2 = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter_1, &10_000_i128);
+    token_admin.mint(&renter_2, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    let booking_id_1 = contract.reserve(&renter_2, &car_id, &(now + 3 * DAY), &(now + 4 * DAY), &token_client.address);
+    let booking_id_2 = contract.reserve(&renter_1, &car_id, &(now + DAY), &(now + 3 * DAY), &token_client.address);
+
+    assert_ne!(booking_id_1, booking_id_2);
+
+    // Bookings are kept in chronological order
+    let bookings = contract.get_bookings(&car_id, &now, &(now + 10 * DAY));
+    assert_eq!(bookings.len(), 2);
+    assert_eq!(bookings.get(0).unwrap().booking_id, booking_id_2);
+    assert_eq!(bookings.get(1).unwrap().booking_id, booking_id_1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+pub fn test_reserve_overlapping_booking_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter_1 = Address::generate(&env);
+    let renter_2 = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter_1, &10_000_i128);
+    token_admin.mint(&renter_2, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract.reserve(&renter_1, &car_id, &(now + DAY), &(now + 3 * DAY), &token_client.address);
+    contract.reserve(&renter_2, &car_id, &(now + 2 * DAY), &(now + 4 * DAY), &token_client.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+pub fn test_reserve_during_active_rental_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter_1 = Address::generate(&env);
+    let renter_2 = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter_1, &10_000_i128);
+    token_admin.mint(&renter_2, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract.rental(&renter_1, &car_id, &2, &3000, &token_client.address);
+    contract.reserve(&renter_2, &car_id, &(now + DAY), &(now + 3 * DAY), &token_client.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+pub fn test_rental_overlapping_booking_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter_1 = Address::generate(&env);
+    let renter_2 = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter_1, &10_000_i128);
+    token_admin.mint(&renter_2, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract.reserve(&renter_1, &car_id, &(now + DAY), &(now + 2 * DAY), &token_client.address);
+    contract.rental(&renter_2, &car_id, &2, &3000, &token_client.address);
+}
+
+#[test]
+pub fn test_expired_booking_is_forfeited_to_owner() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter_1 = Address::generate(&env);
+    let renter_2 = Address::generate(&env);
+    let commission = 100_i128;
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter_1, &10_000_i128);
+    token_admin.mint(&renter_2, &10_000_i128);
+
+    contract.set_admin_commission(&commission);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract.reserve(&renter_1, &car_id, &(now + DAY), &(now + 2 * DAY), &token_client.address);
+
+    // The booking window passes without a pickup
+    env.ledger().set_timestamp(now + 3 * DAY);
+    contract.reserve(&renter_2, &car_id, &(now + 3 * DAY), &(now + 4 * DAY), &token_client.address);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, 1500);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), commission);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, 1500 + commission);
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, 1500 + commission);
+
+    let bookings = contract.get_bookings(&car_id, &now, &(now + 10 * DAY));
+    assert_eq!(bookings.len(), 1);
+    assert_eq!(bookings.get(0).unwrap().renter, renter_2);
+}
+
+#[test]
+pub fn test_expired_booking_is_refunded_when_car_was_still_rented() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter_1 = Address::generate(&env);
+    let renter_2 = Address::generate(&env);
+    let renter_3 = Address::generate(&env);
+    let commission = 100_i128;
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter_1, &10_000_i128);
+    token_admin.mint(&renter_2, &10_000_i128);
+    token_admin.mint(&renter_3, &10_000_i128);
+
+    contract.set_admin_commission(&commission);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter_1, &car_id, &1, &contract.get_quote(&car_id, &1).total, &token_client.address);
+    let booking_id = contract.reserve(&renter_2, &car_id, &(now + DAY), &(now + 2 * DAY), &token_client.address);
+
+    // The walk-in renter brings the car back after the whole booking window
+    env.ledger().set_timestamp(now + 3 * DAY);
+    contract.return_car(&renter_1, &car_id);
+    contract.reserve(&renter_3, &car_id, &(now + 4 * DAY), &(now + 5 * DAY), &token_client.address);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(token_client.balance(&renter_2), 10_000);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), commission);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, 1500);
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, 1500 + commission);
+
+    assert_eq!(
+        contract_events.slice(0..1),
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "booking_refunded").as_val(),
+                    renter_2.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (booking_id, 1500 + commission).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_expired_booking_is_refunded_when_car_came_back_late() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter_1 = Address::generate(&env);
+    let renter_2 = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter_1, &10_000_i128);
+    token_admin.mint(&renter_2, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter_1, &car_id, &1, &1500, &token_client.address);
+    contract.reserve(&renter_2, &car_id, &(now + DAY), &(now + 3 * DAY), &token_client.address);
+
+    // Back in the middle of the booking window, which then ends without a pickup
+    env.ledger().set_timestamp(now + 2 * DAY);
+    contract.return_car(&renter_1, &car_id);
+
+    env.ledger().set_timestamp(now + 4 * DAY);
+    contract.rental(&renter_1, &car_id, &1, &1500, &token_client.address);
+
+    assert_eq!(token_client.balance(&renter_2), 10_000);
+    assert_eq!(contract.get_bookings(&car_id, &now, &(now + 10 * DAY)).len(), 0);
+}
+
+#[test]
+pub fn test_expired_booking_is_refunded_when_car_went_into_maintenance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.reserve(&renter, &car_id, &(now + DAY), &(now + 2 * DAY), &token_client.address);
+
+    env.ledger().set_timestamp(now + DAY + 60);
+    contract.start_maintenance(&owner, &car_id, &String::from_str(&env, "Brakes"));
+
+    env.ledger().set_timestamp(now + 3 * DAY);
+    contract.end_maintenance(&owner, &car_id);
+    contract.reserve(&renter, &car_id, &(now + 4 * DAY), &(now + 5 * DAY), &token_client.address);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - 1500);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+pub fn test_reserve_in_the_past_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let (token_client, _, _) = token;
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.reserve(&renter, &car_id, &(now - DAY), &(now + DAY), &token_client.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+pub fn test_reserve_empty_range_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let (token_client, _, _) = token;
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.reserve(&renter, &car_id, &(now + DAY), &(now + DAY), &token_client.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+pub fn test_owner_cannot_reserve_own_car() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let (token_client, _, _) = token;
+
+    let owner = Address::generate(&env);
+    let now = 1_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(now);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.reserve(&owner, &car_id, &(now + DAY), &(now + 2 * DAY), &token_client.address);
+}
@@ -1,5 +1,6 @@
-use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
-use crate::{storage::{car::read_car, rental::has_rental}, storage::types::car_status::CarStatus, tests::config::contract::ContractTest};
+use crate::tests::config::utils::create_car_details;
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::{storage::{car::read_car, escrow::read_escrow_balance, rental::has_rental}, storage::types::car_status::CarStatus, tests::config::contract::ContractTest};
 use crate::tests::config::utils::get_contract_events;
 
 #[test]
@@ -14,37 +15,39 @@ pub fn test_return_car_successfully() {
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
 
     // Verify car is rented
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Rented);
 
     // Verify rental exists
     let rental_exists = env.as_contract(&contract.address, || {
-        has_rental(&env, &renter, &owner)
+        has_rental(&env, car_id)
     });
-    assert_eq!(rental_exists, true);
+    assert!(rental_exists);
 
-    // Return the car
-    contract.return_car(&renter, &owner);
+    // Return the car two days later
+    let returned_ts = env.ledger().timestamp() + 2 * 86_400;
+    env.ledger().set_timestamp(returned_ts);
+    contract.return_car(&renter, &car_id);
     let contract_events = get_contract_events(&env, &contract.address);
 
     // Verify car is available again
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Available);
 
     // Verify rental is removed
     let rental_exists = env.as_contract(&contract.address, || {
-        has_rental(&env, &renter, &owner)
+        has_rental(&env, car_id)
     });
-    assert_eq!(rental_exists, false);
+    assert!(!rental_exists);
 
     // Verify event
     assert_eq!(
@@ -58,8 +61,9 @@ pub fn test_return_car_successfully() {
                     *Symbol::new(&env, "car_returned").as_val(),
                     renter.clone().into_val(&env),
                     owner.clone().into_val(&env),
+                    car_id.into_val(&env),
                 ],
-                ().into_val(&env)
+                (token_client.address.clone(), returned_ts, 0_i128, 0_i128).into_val(&env)
             )
         ]
     );
@@ -70,13 +74,13 @@ pub fn test_return_car_successfully() {
 pub fn test_return_car_without_rental_fails() {
     let ContractTest { contract, env, .. } = ContractTest::setup();
 
-    let owner = Address::generate(&env);
+    let car_id = 0_u32;
     let renter = Address::generate(&env);
 
     env.mock_all_auths();
 
     // Try to return a car that doesn't exist (will fail with CarNotFound)
-    contract.return_car(&renter, &owner);
+    contract.return_car(&renter, &car_id);
 }
 
 #[test]
@@ -90,10 +94,10 @@ pub fn test_return_car_when_car_not_rented_fails() {
 
     env.mock_all_auths();
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
 
     // Try to return a car that is available, not rented (no rental exists)
-    contract.return_car(&renter, &owner);
+    contract.return_car(&renter, &car_id);
 }
 
 #[test]
@@ -108,16 +112,16 @@ pub fn test_return_car_self_return_fails() {
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&owner, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&owner, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.rental(&owner, &car_id, &total_days, &amount, &token_client.address);
 
     // Owner tries to return their own car (should fail at rental, but test here too)
-    contract.return_car(&owner, &owner);
+    contract.return_car(&owner, &car_id);
 }
 
 #[test]
@@ -134,16 +138,16 @@ pub fn test_unauthorized_user_cannot_return_car() {
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token_client.address);
 
     // Unauthorized user tries to return the car (will fail because no rental exists for unauthorized)
-    contract.return_car(&unauthorized, &owner);
+    contract.return_car(&unauthorized, &car_id);
 }
 
 #[test]
@@ -159,32 +163,93 @@ pub fn test_return_car_multiple_rentals_and_returns() {
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter1, &amount_mint);
     token_admin.mint(&renter2, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
 
     // First rental
-    contract.rental(&renter1, &owner, &total_days, &amount);
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    contract.rental(&renter1, &car_id, &total_days, &amount, &token_client.address);
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Rented);
 
     // Return car
-    contract.return_car(&renter1, &owner);
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    contract.return_car(&renter1, &car_id);
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Available);
 
     // Second rental
-    contract.rental(&renter2, &owner, &total_days, &amount);
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    contract.rental(&renter2, &car_id, &total_days, &amount, &token_client.address);
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Rented);
 
     // Return car again
-    contract.return_car(&renter2, &owner);
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    contract.return_car(&renter2, &car_id);
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Available);
 }
 
+
+#[test]
+pub fn test_return_car_refunds_full_deposit() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+    let deposit = 1000_i128;
+    let amount_mint = 10_000_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.rental(&renter, &car_id, &total_days, &contract.get_quote(&car_id, &total_days).total, &token_client.address);
+
+    contract.return_car(&renter, &car_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - amount);
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, 0);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, amount);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "deposit_refunded").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                deposit.into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_returned").as_val(),
+                    renter.clone().into_val(&env),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (token_client.address.clone(), env.ledger().timestamp(), 0_i128, 0_i128).into_val(&env)
+            )
+        ]
+    );
+}
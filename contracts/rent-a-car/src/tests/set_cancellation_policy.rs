@@ -0,0 +1,110 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
+use crate::storage::structs::cancellation_policy::{CancellationPolicy, CancellationTier};
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, create_policy, get_contract_events}};
+
+#[test]
+pub fn test_set_cancellation_policy_successfully() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let policy = create_policy(&env, true);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_cancellation_policy(&policy);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_cancellation_policy(&car_id), policy);
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![&env, *Symbol::new(&env, "cancellation_policy_set").as_val()],
+                policy.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_default_cancellation_policy_has_no_refunds() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let policy = contract.get_cancellation_policy(&car_id);
+
+    assert_eq!(policy.tiers.len(), 0);
+    assert!(!policy.refund_commission);
+}
+
+#[test]
+pub fn test_car_cancellation_policy_overrides_global_policy() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let global_policy = create_policy(&env, true);
+    let car_policy = CancellationPolicy {
+        tiers: vec![&env, CancellationTier { min_hours_before: 0, refund_bps: 2_500 }],
+        refund_commission: false,
+    };
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let other_car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract.set_cancellation_policy(&global_policy);
+    contract.set_car_cancellation_policy(&car_id, &car_policy);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_cancellation_policy(&car_id), car_policy);
+    assert_eq!(contract.get_cancellation_policy(&other_car_id), global_policy);
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_cancellation_policy_set").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                car_policy.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #27)")]
+pub fn test_set_cancellation_policy_refund_above_100_percent_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let policy = CancellationPolicy {
+        tiers: vec![&env, CancellationTier { min_hours_before: 48, refund_bps: 10_001 }],
+        refund_commission: false,
+    };
+
+    env.mock_all_auths();
+
+    contract.set_cancellation_policy(&policy);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+pub fn test_set_car_cancellation_policy_car_not_found() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.set_car_cancellation_policy(&0, &create_policy(&env, false));
+}
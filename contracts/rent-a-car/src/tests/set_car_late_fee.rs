@@ -0,0 +1,52 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
+use crate::storage::car::read_car;
+use crate::storage::types::late_fee_unit::LateFeeUnit;
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, get_contract_events}};
+
+#[test]
+pub fn test_set_car_late_fee_successfully() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let late_fee = 100_i128;
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_late_fee(&car_id, &late_fee, &LateFeeUnit::Hour);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
+    assert_eq!(car.late_fee, late_fee);
+    assert_eq!(car.late_fee_unit, LateFeeUnit::Hour);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "late_fee_set").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (late_fee, LateFeeUnit::Hour).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+pub fn test_set_car_late_fee_negative_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract.set_car_late_fee(&car_id, &-1, &LateFeeUnit::Day);
+}
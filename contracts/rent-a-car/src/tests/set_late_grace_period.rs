@@ -0,0 +1,33 @@
+use soroban_sdk::{vec, IntoVal, Symbol};
+use crate::storage::admin::read_late_grace_period;
+use crate::tests::config::{contract::ContractTest, utils::get_contract_events};
+
+#[test]
+pub fn test_set_late_grace_period_successfully() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let grace_period = 1_800_u64;
+
+    contract.set_late_grace_period(&grace_period);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let stored = env.as_contract(&contract.address, || read_late_grace_period(&env));
+    assert_eq!(stored, grace_period);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "late_grace_period_set").as_val(),
+                ],
+                grace_period.into_val(&env)
+            )
+        ]
+    );
+}
@@ -0,0 +1,327 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol, Vec};
+use crate::storage::contract_balance::read_contract_balance;
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, create_token_contract, get_contract_events}};
+use crate::storage::structs::earnings_statement::EarningsStatement;
+use crate::storage::structs::settle_cursor::SettleCursor;
+
+// Per transaction limits of the network
+const TX_MAX_FOOTPRINT_ENTRIES: u32 = 100;
+const TX_MAX_WRITE_ENTRIES: u32 = 50;
+const TX_MAX_WRITE_BYTES: u32 = 132_096;
+const TX_MAX_CONTRACT_EVENTS_BYTES: u32 = 16_384;
+
+#[test]
+pub fn test_settle_many_pays_opted_in_owners() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let manual_owner = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let first_car = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let second_car = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let manual_car = contract.add_car(&manual_owner, &1500_i128, &create_car_details(&env));
+
+    for car_id in [first_car, second_car, manual_car] {
+        contract.rental(&renter, &car_id, &1, &amount, &token_client.address);
+        contract.return_car(&renter, &car_id);
+    }
+
+    // Opting in after the returns leaves the earnings for the batch
+    contract.set_payout_settings(&owner, &treasury, &true);
+
+    let cursor = contract.settle_many(&admin, &vec![&env, owner.clone(), manual_owner.clone()], &SettleCursor { owner: 0, car: 0 }, &10);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(cursor, None);
+    assert_eq!(token_client.balance(&treasury), 2 * amount);
+    assert_eq!(contract.get_car_balance(&first_car, &token_client.address).settled, 0);
+    assert_eq!(contract.get_car_balance(&second_car, &token_client.address).settled, 0);
+    assert_eq!(contract.get_car_balance(&manual_car, &token_client.address).settled, amount);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, amount);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "statement_entry").as_val(),
+                    owner.clone().into_val(&env),
+                    first_car.into_val(&env),
+                ],
+                (
+                    token_client.address.clone(),
+                    EarningsStatement { rental_count: 0, total_earned: 0, total_refunded: 0, total_withdrawn: amount, fees_paid: 0 },
+                ).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "statement_entry").as_val(),
+                    owner.clone().into_val(&env),
+                    second_car.into_val(&env),
+                ],
+                (
+                    token_client.address.clone(),
+                    EarningsStatement { rental_count: 0, total_earned: 0, total_refunded: 0, total_withdrawn: amount, fees_paid: 0 },
+                ).into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "owner_settled").as_val(),
+                    owner.clone().into_val(&env),
+                ],
+                (treasury.clone(), vec![&env, (token_client.address.clone(), 2 * amount)]).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_settle_many_returns_cursor_when_limited() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let mut owners = Vec::new(&env);
+    for _ in 0..3 {
+        let owner = Address::generate(&env);
+        let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+        contract.rental(&renter, &car_id, &1, &amount, &token_client.address);
+        contract.return_car(&renter, &car_id);
+        contract.set_payout_settings(&owner, &owner, &true);
+        owners.push_back(owner);
+    }
+
+    // The limit caps the cars settled by one call
+    let cursor = contract.settle_many(&admin, &owners, &SettleCursor { owner: 0, car: 0 }, &2);
+    assert_eq!(cursor, Some(SettleCursor { owner: 2, car: 0 }));
+    assert_eq!(token_client.balance(&owners.get(0).unwrap()), amount);
+    assert_eq!(token_client.balance(&owners.get(1).unwrap()), amount);
+    assert_eq!(token_client.balance(&owners.get(2).unwrap()), 0);
+
+    let cursor = contract.settle_many(&admin, &owners, &cursor.unwrap(), &2);
+    assert_eq!(cursor, None);
+    assert_eq!(token_client.balance(&owners.get(2).unwrap()), amount);
+}
+
+#[test]
+pub fn test_settle_many_splits_an_owner_with_many_cars() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let mut car_ids = Vec::new(&env);
+    for _ in 0..3 {
+        let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+        contract.rental(&renter, &car_id, &1, &amount, &token_client.address);
+        contract.return_car(&renter, &car_id);
+        car_ids.push_back(car_id);
+    }
+    contract.set_payout_settings(&owner, &owner, &true);
+
+    let owners = vec![&env, owner.clone()];
+
+    let cursor = contract.settle_many(&admin, &owners, &SettleCursor { owner: 0, car: 0 }, &2);
+    assert_eq!(cursor, Some(SettleCursor { owner: 0, car: 2 }));
+    assert_eq!(token_client.balance(&owner), 2 * amount);
+    assert_eq!(contract.get_car_balance(&car_ids.get(2).unwrap(), &token_client.address).settled, amount);
+
+    let cursor = contract.settle_many(&admin, &owners, &cursor.unwrap(), &2);
+    assert_eq!(cursor, None);
+    assert_eq!(token_client.balance(&owner), 3 * amount);
+}
+
+#[test]
+pub fn test_settle_many_skips_owner_with_nothing_settled() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_payout_settings(&owner, &owner, &true);
+
+    let cursor = contract.settle_many(&admin, &vec![&env, owner.clone()], &SettleCursor { owner: 0, car: 0 }, &5);
+
+    assert_eq!(cursor, None);
+    assert_eq!(get_contract_events(&env, &contract.address), vec![&env]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_settle_many_with_zero_limit_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.settle_many(&admin, &vec![&env, Address::generate(&env)], &SettleCursor { owner: 0, car: 0 }, &0);
+}
+
+#[test]
+pub fn test_settle_many_stays_within_network_limits() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, token_issuer) = token;
+    token_admin.mint(&renter, &1_000_000);
+
+    // A second allowed token doubles what every car costs, even when nothing is settled in it
+    let (second_client, _) = create_token_contract(&env, &token_issuer);
+    contract.allow_token(&second_client.address);
+
+    let mut owners = Vec::new(&env);
+    for _ in 0..3 {
+        let owner = Address::generate(&env);
+        for _ in 0..20 {
+            let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+            contract.rental(&renter, &car_id, &1, &amount, &token_client.address);
+            contract.return_car(&renter, &car_id);
+        }
+        contract.set_payout_settings(&owner, &owner, &true);
+        owners.push_back(owner);
+    }
+
+    let mut cursor = Some(SettleCursor { owner: 0, car: 0 });
+    let mut calls = 0;
+
+    while let Some(next) = cursor {
+        cursor = contract.settle_many(&admin, &owners, &next, &100);
+        calls += 1;
+
+        let resources = env.cost_estimate().resources();
+        assert!(resources.memory_read_entries <= TX_MAX_FOOTPRINT_ENTRIES);
+        assert!(resources.write_entries <= TX_MAX_WRITE_ENTRIES);
+        assert!(resources.write_bytes <= TX_MAX_WRITE_BYTES);
+        assert!(resources.contract_events_size_bytes <= TX_MAX_CONTRACT_EVENTS_BYTES);
+    }
+
+    // The writes of two tokens leave room for four cars per call
+    assert_eq!(calls, 15);
+    for owner in owners.iter() {
+        assert_eq!(token_client.balance(&owner), 20 * amount);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #46)")]
+pub fn test_settle_many_without_budget_for_one_owner_fails() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (_, _, token_issuer) = token;
+    for _ in 0..10 {
+        let (client, _) = create_token_contract(&env, &token_issuer);
+        contract.allow_token(&client.address);
+    }
+
+    contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_payout_settings(&owner, &owner, &true);
+
+    contract.settle_many(&admin, &vec![&env, owner], &SettleCursor { owner: 0, car: 0 }, &10);
+}
+
+#[test]
+pub fn test_keeper_can_settle_many() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &amount, &token_client.address);
+    contract.return_car(&renter, &car_id);
+    contract.set_payout_settings(&owner, &owner, &true);
+
+    contract.set_settlement_keeper(&Some(keeper.clone()));
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_settlement_keeper(), Some(keeper.clone()));
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![&env, *Symbol::new(&env, "settlement_keeper_set").as_val()],
+                Some(keeper.clone()).into_val(&env)
+            )
+        ]
+    );
+
+    let cursor = contract.settle_many(&keeper, &vec![&env, owner.clone()], &SettleCursor { owner: 0, car: 0 }, &10);
+
+    assert_eq!(cursor, None);
+    assert_eq!(token_client.balance(&owner), amount);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+pub fn test_settle_many_by_removed_keeper_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let keeper = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.set_settlement_keeper(&Some(keeper.clone()));
+    contract.set_settlement_keeper(&None);
+    assert_eq!(contract.get_settlement_keeper(), None);
+
+    contract.settle_many(&keeper, &vec![&env, Address::generate(&env)], &SettleCursor { owner: 0, car: 0 }, &10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+pub fn test_settle_many_by_stranger_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.settle_many(&stranger, &vec![&env, Address::generate(&env)], &SettleCursor { owner: 0, car: 0 }, &10);
+}
@@ -0,0 +1,141 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::storage::structs::early_return_policy::EarlyReturnPolicy;
+use crate::storage::structs::earnings_statement::EarningsStatement;
+use crate::storage::types::fee_bearer::FeeBearer;
+use crate::tests::config::{contract::ContractTest, utils::create_car_details};
+
+const DAY: u64 = 86_400;
+
+#[test]
+pub fn test_owner_statement_is_empty_by_default() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let (token_client, _, _) = token;
+
+    assert_eq!(contract.get_owner_statement(&owner, &token_client.address), EarningsStatement::empty());
+}
+
+#[test]
+pub fn test_statement_tracks_rental_lifecycle() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let deposit = 500_i128;
+    let withheld = 200_i128;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000_000);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &20_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.rental(&renter, &car_id, &2, &contract.get_quote(&car_id, &2).total, &token_client.address);
+    contract.extend_rental(&renter, &car_id, &1);
+    contract.withhold_deposit(&car_id, &withheld, &1);
+
+    env.ledger().set_timestamp(1_000_000 + 3 * DAY);
+    contract.return_car(&renter, &car_id);
+    contract.payout_owner(&car_id, &token_client.address, &2000);
+
+    let expected = EarningsStatement {
+        rental_count: 1,
+        total_earned: 4500 + withheld,
+        total_refunded: 0,
+        total_withdrawn: 2000,
+        fees_paid: 0,
+    };
+
+    assert_eq!(contract.get_car_statement(&car_id, &token_client.address), expected);
+    assert_eq!(contract.get_owner_statement(&owner, &token_client.address), expected);
+
+    // What is left to withdraw always matches the statement
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, expected.total_earned - expected.total_refunded - expected.total_withdrawn);
+}
+
+#[test]
+pub fn test_owner_statement_adds_up_cars_and_fees() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission = 100_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &20_000);
+
+    contract.set_admin_commission(&commission);
+    contract.set_owner_fee_bearer(&owner, &FeeBearer::Owner);
+
+    let first_car = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    let second_car = contract.add_car(&owner, &1000_i128, &create_car_details(&env));
+
+    contract.rental(&renter, &first_car, &1, &1500, &token_client.address);
+    contract.return_car(&renter, &first_car);
+    contract.rental(&renter, &second_car, &1, &1000, &token_client.address);
+
+    assert_eq!(
+        contract.get_car_statement(&second_car, &token_client.address),
+        EarningsStatement {
+            rental_count: 1,
+            total_earned: 1000 - commission,
+            total_refunded: 0,
+            total_withdrawn: 0,
+            fees_paid: commission,
+        }
+    );
+
+    assert_eq!(
+        contract.get_owner_statement(&owner, &token_client.address),
+        EarningsStatement {
+            rental_count: 2,
+            total_earned: 2500 - 2 * commission,
+            total_refunded: 0,
+            total_withdrawn: 0,
+            fees_paid: 2 * commission,
+        }
+    );
+}
+
+#[test]
+pub fn test_statement_records_early_return_refund() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1000_i128;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000_000);
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.set_car_early_return_policy(&car_id, &EarlyReturnPolicy { enabled: true, fee: 0 });
+    contract.rental(&renter, &car_id, &3, &3000, &token_client.address);
+
+    env.ledger().set_timestamp(1_000_000 + DAY);
+    contract.return_car(&renter, &car_id);
+
+    let statement = contract.get_owner_statement(&owner, &token_client.address);
+    assert_eq!(statement.total_earned, 3 * price_per_day);
+    assert_eq!(statement.total_refunded, 2 * price_per_day);
+    assert_eq!(contract.get_car_balance(&car_id, &token_client.address).settled, price_per_day);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+pub fn test_get_car_statement_for_missing_car_fails() {
+    let ContractTest { contract, token, .. } = ContractTest::setup();
+
+    let (token_client, _, _) = token;
+
+    contract.get_car_statement(&7, &token_client.address);
+}
@@ -0,0 +1,212 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
+use crate::storage::contract_balance::read_contract_balance;
+use crate::storage::structs::owner_volume::OwnerVolume;
+use crate::tests::config::{contract::ContractTest, utils::{bps_config, create_car_details, create_token_contract, get_contract_events}};
+
+#[test]
+pub fn test_constructor_allows_initial_token() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let (token_client, _, _) = token;
+
+    assert_eq!(contract.get_allowed_tokens(), vec![&env, token_client.address.clone()]);
+
+    let owner = Address::generate(&env);
+    env.mock_all_auths();
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    assert_eq!(contract.get_car_tokens(&car_id), vec![&env, token_client.address.clone()]);
+}
+
+#[test]
+pub fn test_rental_in_second_token_keeps_balances_apart() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 3000_i128;
+    let commission = 150_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, _, token_issuer) = token;
+    let (second_client, second_admin) = create_token_contract(&env, &token_issuer);
+    second_admin.mint(&renter, &10_000);
+
+    contract.allow_token(&second_client.address);
+    contract.set_commission_config(&bps_config(500, None, None));
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_tokens(&car_id, &vec![&env, token_client.address.clone(), second_client.address.clone()]);
+    contract.rental(&renter, &car_id, &2, &contract.get_quote(&car_id, &2).total, &second_client.address);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(second_client.balance(&renter), 10_000 - amount - commission);
+    assert_eq!(contract.get_car_balance(&car_id, &second_client.address).settled, amount);
+    assert_eq!(contract.get_car_balance(&car_id, &token_client.address).settled, 0);
+    assert_eq!(contract.get_admin_available_to_withdraw(&second_client.address), commission);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), 0);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &second_client.address));
+    assert_eq!(contract_balance, amount + commission);
+
+    contract.payout_owner(&car_id, &second_client.address, &amount);
+    assert_eq!(second_client.balance(&owner), amount);
+
+    // Revenue thresholds are in the reference token, the rental still counts as completed
+    assert_eq!(contract.get_owner_volume(&owner), OwnerVolume { completed_rentals: 1, revenue: 0 });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #45)")]
+pub fn test_rental_in_second_token_with_flat_commission_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, _, token_issuer) = token;
+    let (second_client, second_admin) = create_token_contract(&env, &token_issuer);
+    second_admin.mint(&renter, &10_000);
+
+    contract.allow_token(&second_client.address);
+    contract.set_admin_commission(&100);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_tokens(&car_id, &vec![&env, token_client.address.clone(), second_client.address.clone()]);
+    contract.rental(&renter, &car_id, &1, &5000, &second_client.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #45)")]
+pub fn test_rental_in_second_token_with_max_fee_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, _, token_issuer) = token;
+    let (second_client, second_admin) = create_token_contract(&env, &token_issuer);
+    second_admin.mint(&renter, &10_000);
+
+    contract.allow_token(&second_client.address);
+    contract.set_commission_config(&bps_config(500, None, Some(50)));
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_tokens(&car_id, &vec![&env, token_client.address.clone(), second_client.address.clone()]);
+    contract.rental(&renter, &car_id, &1, &5000, &second_client.address);
+}
+
+#[test]
+pub fn test_disallowed_token_balance_can_still_be_withdrawn() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.rental(&renter, &car_id, &1, &amount, &token_client.address);
+    contract.return_car(&renter, &car_id);
+
+    contract.disallow_token(&token_client.address);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_allowed_tokens(), vec![&env]);
+
+    contract.payout_owner(&car_id, &token_client.address, &amount);
+    assert_eq!(token_client.balance(&owner), amount);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "token_disallowed").as_val(),
+                    token_client.address.clone().into_val(&env),
+                ],
+                ().into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #36)")]
+pub fn test_rental_in_disallowed_token_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.disallow_token(&token_client.address);
+
+    contract.rental(&renter, &car_id, &1, &1500, &token_client.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #36)")]
+pub fn test_rental_in_token_not_accepted_by_car_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (_, _, token_issuer) = token;
+    let (second_client, second_admin) = create_token_contract(&env, &token_issuer);
+    second_admin.mint(&renter, &10_000);
+
+    contract.allow_token(&second_client.address);
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+
+    contract.rental(&renter, &car_id, &1, &1500, &second_client.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #35)")]
+pub fn test_set_car_tokens_with_token_not_allowed_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (_, _, token_issuer) = token;
+    let (second_client, _) = create_token_contract(&env, &token_issuer);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_tokens(&car_id, &vec![&env, second_client.address.clone()]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #35)")]
+pub fn test_set_car_tokens_empty_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_tokens(&car_id, &vec![&env]);
+}
@@ -0,0 +1,109 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, String, Symbol};
+use crate::storage::car::read_car;
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, get_contract_events}};
+
+#[test]
+pub fn test_update_car_details_by_owner() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+
+    let mut details = create_car_details(&env);
+    details.color = String::from_str(&env, "Red");
+    details.ac = false;
+
+    contract.update_car_details(&owner, &car_id, &details);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
+    assert_eq!(car.details, details);
+    assert_eq!(car.price_per_day, price_per_day);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_details_updated").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                details.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_update_car_details_by_admin() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+
+    let mut details = create_car_details(&env);
+    details.passengers = 7;
+
+    contract.update_car_details(&admin, &car_id, &details);
+
+    let car = contract.get_car(&car_id);
+    assert_eq!(car.details.passengers, 7);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+pub fn test_update_car_details_by_stranger_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let price_per_day = 1500_i128;
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+
+    contract.update_car_details(&stranger, &car_id, &create_car_details(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+pub fn test_update_car_details_with_invalid_details_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+
+    let mut details = create_car_details(&env);
+    details.model = String::from_str(&env, "");
+
+    contract.update_car_details(&owner, &car_id, &details);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+pub fn test_update_car_details_car_not_found_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.update_car_details(&owner, &0, &create_car_details(&env));
+}
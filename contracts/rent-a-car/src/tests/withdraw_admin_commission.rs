@@ -1,3 +1,4 @@
+use crate::tests::config::utils::create_car_details;
 use soroban_sdk::{testutils::Address as _, Address};
 use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
 use crate::{storage::{admin::read_admin_available_to_withdraw, contract_balance::read_contract_balance}, tests::config::contract::ContractTest};
@@ -11,28 +12,27 @@ pub fn test_withdraw_admin_commission_successfully() {
     let renter = Address::generate(&env);
     let price_per_day = 1500_i128;
     let total_days = 3;
-    let amount = 4500_i128;
     let commission = 500_i128;
     let withdraw_amount = 300_i128;
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
     contract.set_admin_commission(&commission);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &contract.get_quote(&car_id, &total_days).total, &token_client.address);
 
     let initial_admin_available = env.as_contract(&contract.address, || {
-        read_admin_available_to_withdraw(&env)
+        read_admin_available_to_withdraw(&env, &token_client.address)
     });
     assert_eq!(initial_admin_available, commission);
 
     let initial_contract_balance = env.as_contract(&contract.address, || {
-        read_contract_balance(&env)
+        read_contract_balance(&env, &token_client.address)
     });
 
     contract
@@ -41,19 +41,19 @@ pub fn test_withdraw_admin_commission_successfully() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "withdraw_admin_commission",
-                args: (withdraw_amount,).into_val(&env),
+                args: (token_client.address.clone(), withdraw_amount).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .withdraw_admin_commission(&withdraw_amount);
+        .withdraw_admin_commission(&token_client.address, &withdraw_amount);
 
     let updated_admin_available = env.as_contract(&contract.address, || {
-        read_admin_available_to_withdraw(&env)
+        read_admin_available_to_withdraw(&env, &token_client.address)
     });
     assert_eq!(updated_admin_available, commission - withdraw_amount);
 
     let updated_contract_balance = env.as_contract(&contract.address, || {
-        read_contract_balance(&env)
+        read_contract_balance(&env, &token_client.address)
     });
     assert_eq!(updated_contract_balance, initial_contract_balance - withdraw_amount);
 }
@@ -66,19 +66,18 @@ pub fn test_withdraw_admin_commission_full_amount() {
     let renter = Address::generate(&env);
     let price_per_day = 1500_i128;
     let total_days = 3;
-    let amount = 4500_i128;
     let commission = 500_i128;
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
     contract.set_admin_commission(&commission);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &contract.get_quote(&car_id, &total_days).total, &token_client.address);
 
     contract
         .mock_auths(&[MockAuth {
@@ -86,14 +85,14 @@ pub fn test_withdraw_admin_commission_full_amount() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "withdraw_admin_commission",
-                args: (commission,).into_val(&env),
+                args: (token_client.address.clone(), commission).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .withdraw_admin_commission(&commission);
+        .withdraw_admin_commission(&token_client.address, &commission);
 
     let updated_admin_available = env.as_contract(&contract.address, || {
-        read_admin_available_to_withdraw(&env)
+        read_admin_available_to_withdraw(&env, &token_client.address)
     });
     assert_eq!(updated_admin_available, 0);
 }
@@ -101,23 +100,25 @@ pub fn test_withdraw_admin_commission_full_amount() {
 #[test]
 #[should_panic(expected = "Error(Contract, #6)")]
 pub fn test_withdraw_admin_commission_with_zero_amount_fails() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, token, .. } = ContractTest::setup();
+    let (token_client, _, _) = token;
     let amount = 0_i128;
 
     env.mock_all_auths();
 
-    contract.withdraw_admin_commission(&amount);
+    contract.withdraw_admin_commission(&token_client.address, &amount);
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #6)")]
 pub fn test_withdraw_admin_commission_with_negative_amount_fails() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, token, .. } = ContractTest::setup();
+    let (token_client, _, _) = token;
     let amount = -100_i128;
 
     env.mock_all_auths();
 
-    contract.withdraw_admin_commission(&amount);
+    contract.withdraw_admin_commission(&token_client.address, &amount);
 }
 
 #[test]
@@ -129,22 +130,21 @@ pub fn test_withdraw_admin_commission_insufficient_balance_fails() {
     let renter = Address::generate(&env);
     let price_per_day = 1500_i128;
     let total_days = 3;
-    let amount = 4500_i128;
     let commission = 500_i128;
     let withdraw_amount = 1000_i128; // More than available
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
     contract.set_admin_commission(&commission);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &contract.get_quote(&car_id, &total_days).total, &token_client.address);
 
-    contract.withdraw_admin_commission(&withdraw_amount);
+    contract.withdraw_admin_commission(&token_client.address, &withdraw_amount);
 }
 
 #[test]
@@ -157,36 +157,35 @@ pub fn test_withdraw_admin_commission_multiple_rentals() {
     let renter2 = Address::generate(&env);
     let price_per_day = 1500_i128;
     let total_days = 3;
-    let amount1 = 4500_i128;
-    let amount2 = 3000_i128;
+    let total_days2 = 2;
     let commission = 500_i128;
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter1, &amount_mint);
     token_admin.mint(&renter2, &amount_mint);
 
-    contract.add_car(&owner1, &price_per_day);
-    contract.add_car(&owner2, &price_per_day);
+    let car_id1 = contract.add_car(&owner1, &price_per_day, &create_car_details(&env));
+    let car_id2 = contract.add_car(&owner2, &price_per_day, &create_car_details(&env));
     contract.set_admin_commission(&commission);
     
-    contract.rental(&renter1, &owner1, &total_days, &amount1);
-    contract.rental(&renter2, &owner2, &total_days, &amount2);
+    contract.rental(&renter1, &car_id1, &total_days, &contract.get_quote(&car_id1, &total_days).total, &token_client.address);
+    contract.rental(&renter2, &car_id2, &total_days2, &contract.get_quote(&car_id2, &total_days2).total, &token_client.address);
 
     let total_commissions = commission * 2;
     let admin_available = env.as_contract(&contract.address, || {
-        read_admin_available_to_withdraw(&env)
+        read_admin_available_to_withdraw(&env, &token_client.address)
     });
     assert_eq!(admin_available, total_commissions);
 
     let withdraw_amount = 750_i128;
-    contract.withdraw_admin_commission(&withdraw_amount);
+    contract.withdraw_admin_commission(&token_client.address, &withdraw_amount);
 
     let updated_admin_available = env.as_contract(&contract.address, || {
-        read_admin_available_to_withdraw(&env)
+        read_admin_available_to_withdraw(&env, &token_client.address)
     });
     assert_eq!(updated_admin_available, total_commissions - withdraw_amount);
 }
@@ -199,22 +198,21 @@ pub fn test_withdraw_admin_commission_using_getter() {
     let renter = Address::generate(&env);
     let price_per_day = 1500_i128;
     let total_days = 3;
-    let amount = 4500_i128;
     let commission = 500_i128;
     let withdraw_amount = 300_i128;
 
     env.mock_all_auths();
 
-    let (_, token_admin, _) = token;
+    let (token_client, token_admin, _) = token;
 
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
     contract.set_admin_commission(&commission);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &contract.get_quote(&car_id, &total_days).total, &token_client.address);
 
-    let initial_admin_available = contract.get_admin_available_to_withdraw();
+    let initial_admin_available = contract.get_admin_available_to_withdraw(&token_client.address);
     assert_eq!(initial_admin_available, commission, 
         "Expected initial available commission to be {}", commission);
 
@@ -224,13 +222,13 @@ pub fn test_withdraw_admin_commission_using_getter() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "withdraw_admin_commission",
-                args: (withdraw_amount,).into_val(&env),
+                args: (token_client.address.clone(), withdraw_amount).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .withdraw_admin_commission(&withdraw_amount);
+        .withdraw_admin_commission(&token_client.address, &withdraw_amount);
 
-    let updated_admin_available = contract.get_admin_available_to_withdraw();
+    let updated_admin_available = contract.get_admin_available_to_withdraw(&token_client.address);
     assert_eq!(updated_admin_available, commission - withdraw_amount,
         "Expected updated available commission to be {} after withdrawing {}", 
         commission - withdraw_amount, withdraw_amount);
@@ -0,0 +1,120 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
+use crate::storage::{contract_balance::read_contract_balance, escrow::read_escrow_balance};
+use crate::tests::config::{contract::ContractTest, utils::{create_car_details, get_contract_events}};
+
+#[test]
+pub fn test_withhold_deposit_is_settled_on_return() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+    let deposit = 1000_i128;
+    let withheld = 400_i128;
+    let reason = 2_u32;
+    let amount_mint = 10_000_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&owner, &price_per_day, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &deposit);
+    contract.rental(&renter, &car_id, &total_days, &contract.get_quote(&car_id, &total_days).total, &token_client.address);
+
+    contract.withhold_deposit(&car_id, &withheld, &reason);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "deposit_withheld").as_val(),
+                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (withheld, reason).into_val(&env)
+            )
+        ]
+    );
+
+    let rental = contract.get_rental(&car_id);
+    assert_eq!(rental.deposit_withheld, withheld);
+    assert_eq!(rental.withhold_reason, reason);
+
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - amount - withheld);
+
+    let balance = contract.get_car_balance(&car_id, &token_client.address);
+    assert_eq!(balance.settled, amount + withheld);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, amount + withheld);
+
+    let escrow_balance = env.as_contract(&contract.address, || read_escrow_balance(&env, &token_client.address));
+    assert_eq!(escrow_balance, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+pub fn test_withhold_more_than_deposit_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &1000);
+    contract.rental(&renter, &car_id, &3, &contract.get_quote(&car_id, &3).total, &token_client.address);
+
+    contract.withhold_deposit(&car_id, &600, &1);
+    contract.withhold_deposit(&car_id, &600, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+pub fn test_withhold_deposit_without_rental_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &1000);
+
+    contract.withhold_deposit(&car_id, &500, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+pub fn test_withhold_deposit_zero_amount_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&owner, &1500_i128, &create_car_details(&env));
+    contract.set_car_deposit(&car_id, &1000);
+    contract.rental(&renter, &car_id, &3, &contract.get_quote(&car_id, &3).total, &token_client.address);
+
+    contract.withhold_deposit(&car_id, &0, &1);
+}
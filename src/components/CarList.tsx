@@ -6,7 +6,7 @@ import { UserRole } from "../interfaces/user-role";
 import { stellarService } from "../services/stellar.service";
 import { walletService } from "../services/wallet.service";
 import { shortenAddress } from "../utils/shorten-address";
-import { ONE_XLM_IN_STROOPS } from "../utils/xlm-in-stroops";
+import { TOKEN_ADDRESS } from "../utils/contants";
 import {useStellarAccounts} from "../providers/StellarAccountProvider.tsx";
 import { useState } from "react";
 import useModal from "../hooks/useModal";
@@ -24,21 +24,21 @@ export const CarsList = ({ cars }: CarsListProps) => {
     const withdrawModal = useModal();
     const [selectedCar, setSelectedCar] = useState<ICar | null>(null);
     const [ownerAvailableAmount, setOwnerAvailableAmount] = useState<number>(0);
-    const [isDeleting, setIsDeleting] = useState<string | null>(null);
-    const [isReturning, setIsReturning] = useState<string | null>(null);
+    const [isDeleting, setIsDeleting] = useState<number | null>(null);
+    const [isReturning, setIsReturning] = useState<number | null>(null);
 
-    const handleDelete = async (owner: string) => {
+    const handleDelete = async (carId: number) => {
         // Prevenir ejecuciones duplicadas
-        if (isDeleting === owner) {
+        if (isDeleting === carId) {
             return;
         }
         
-        setIsDeleting(owner);
+        setIsDeleting(carId);
         try {
             const contractClient =
                 await stellarService.buildClient<IRentACarContract>(walletAddress);
 
-            const result = await contractClient.remove_car({ owner });
+            const result = await contractClient.remove_car({ car_id: carId });
             const xdr = result.toXDR();
 
             const signedTx = await walletService.signTransaction(xdr);
@@ -49,7 +49,7 @@ export const CarsList = ({ cars }: CarsListProps) => {
                 throw new Error("La transacción no fue procesada correctamente.");
             }
 
-            setCars((prev) => prev.filter((car) => car.ownerAddress !== owner));
+            setCars((prev) => prev.filter((car) => car.carId !== carId));
             setHashId(txHash);
             toast.success("Vehículo eliminado exitosamente.");
         } catch (error) {
@@ -61,12 +61,16 @@ export const CarsList = ({ cars }: CarsListProps) => {
         }
     };
 
-    const handlePayout = async (owner: string, amount: number) => {
+    const handlePayout = async (carId: number, amount: number) => {
         try {
             const contractClient =
                 await stellarService.buildClient<IRentACarContract>(walletAddress);
 
-            const result = await contractClient.payout_owner({ owner, amount });
+            const result = await contractClient.payout_owner({
+                car_id: carId,
+                token: TOKEN_ADDRESS,
+                amount,
+            });
             const xdr = result.toXDR();
 
             const signedTx = await walletService.signTransaction(xdr);
@@ -81,9 +85,9 @@ export const CarsList = ({ cars }: CarsListProps) => {
             toast.success("Fondos retirados exitosamente.");
             
             // Refresh available amount after withdrawal
-            if (selectedCar && selectedCar.ownerAddress === owner) {
+            if (selectedCar && selectedCar.carId === carId) {
                 try {
-                    const available = await stellarService.getOwnerAvailableToWithdraw(owner);
+                    const available = await stellarService.getCarAvailableToWithdraw(carId);
                     setOwnerAvailableAmount(available);
                 } catch (error) {
                     console.error("Error refreshing available amount:", error);
@@ -102,7 +106,7 @@ export const CarsList = ({ cars }: CarsListProps) => {
         setSelectedCar(car);
         try {
             // Load available amount before opening modal
-            const available = await stellarService.getOwnerAvailableToWithdraw(car.ownerAddress);
+            const available = await stellarService.getCarAvailableToWithdraw(car.carId);
             setOwnerAvailableAmount(available);
             withdrawModal.openModal();
         } catch (error) {
@@ -120,14 +124,19 @@ export const CarsList = ({ cars }: CarsListProps) => {
             const contractClient =
                 await stellarService.buildClient<IRentACarContract>(walletAddress);
 
-            const rentalAmount = car.pricePerDay * totalDaysToRent * ONE_XLM_IN_STROOPS;
-            
-            // Note: Commission is automatically added to the deposit by the contract
+            // The contract prices the rental and pulls the quote total with commission and deposit,
+            // amount is the most the renter agrees to pay
+            const { result: quote } = await contractClient.get_quote({
+                car_id: car.carId,
+                total_days_to_rent: totalDaysToRent,
+            });
+
             const result = await contractClient.rental({
                 renter: walletAddress,
-                owner: car.ownerAddress,
+                car_id: car.carId,
                 total_days_to_rent: totalDaysToRent,
-                amount: rentalAmount,
+                amount: Number(quote.total),
+                token: TOKEN_ADDRESS,
             });
             const xdr = result.toXDR();
 
@@ -141,7 +150,7 @@ export const CarsList = ({ cars }: CarsListProps) => {
 
             setCars((prev) =>
                 prev.map((c) =>
-                    c.ownerAddress === car.ownerAddress
+                    c.carId === car.carId
                         ? { ...c, status: CarStatus.RENTED }
                         : c
                 )
@@ -163,18 +172,18 @@ export const CarsList = ({ cars }: CarsListProps) => {
 
     const handleReturnCar = async (car: ICar) => {
         // Prevenir ejecuciones duplicadas
-        if (isReturning === car.ownerAddress) {
+        if (isReturning === car.carId) {
             return;
         }
         
-        setIsReturning(car.ownerAddress);
+        setIsReturning(car.carId);
         try {
             const contractClient =
                 await stellarService.buildClient<IRentACarContract>(walletAddress);
 
             const result = await contractClient.return_car({
                 renter: walletAddress,
-                owner: car.ownerAddress,
+                car_id: car.carId,
             });
             const xdr = result.toXDR();
 
@@ -188,7 +197,7 @@ export const CarsList = ({ cars }: CarsListProps) => {
 
             setCars((prev) =>
                 prev.map((c) =>
-                    c.ownerAddress === car.ownerAddress
+                    c.carId === car.carId
                         ? { ...c, status: CarStatus.AVAILABLE }
                         : c
                 )
@@ -219,10 +228,10 @@ export const CarsList = ({ cars }: CarsListProps) => {
 
     const renderActionButton = (car: ICar) => {
         if (selectedRole === UserRole.ADMIN) {
-            const isCurrentlyDeleting = isDeleting === car.ownerAddress;
+            const isCurrentlyDeleting = isDeleting === car.carId;
             return (
                 <button
-                    onClick={() => void handleDelete(car.ownerAddress)}
+                    onClick={() => void handleDelete(car.carId)}
                     disabled={isCurrentlyDeleting}
                     className="px-3 py-1 bg-red-600 text-white rounded font-semibold hover:bg-red-700 disabled:bg-gray-400 disabled:cursor-not-allowed transition-colors cursor-pointer"
                 >
@@ -258,7 +267,7 @@ export const CarsList = ({ cars }: CarsListProps) => {
                 );
             }
             if (car.status === CarStatus.RENTED) {
-                const isCurrentlyReturning = isReturning === car.ownerAddress;
+                const isCurrentlyReturning = isReturning === car.carId;
                 return (
                     <button
                         onClick={() => void handleReturnCar(car)}
@@ -310,8 +319,8 @@ export const CarsList = ({ cars }: CarsListProps) => {
                     </tr>
                     </thead>
                     <tbody className="divide-y divide-gray-200">
-                    {cars.map((car, index) => (
-                        <tr key={index} className="hover:bg-gray-50">
+                    {cars.map((car) => (
+                        <tr key={car.carId} className="hover:bg-gray-50">
                             <td className="px-6 py-4 whitespace-nowrap text-sm font-medium text-gray-900">
                                 {car.brand}
                             </td>
@@ -370,7 +379,7 @@ export const CarsList = ({ cars }: CarsListProps) => {
                     }}
                     onWithdraw={handlePayout}
                     availableAmount={ownerAvailableAmount}
-                    ownerAddress={selectedCar.ownerAddress}
+                    carId={selectedCar.carId}
                 />
             )}
         </div>
@@ -5,16 +5,16 @@ import { ONE_XLM_IN_STROOPS } from "../utils/xlm-in-stroops";
 
 interface WithdrawOwnerModalProps {
     closeModal: () => void;
-    onWithdraw: (owner: string, amount: number) => Promise<void>;
+    onWithdraw: (carId: number, amount: number) => Promise<void>;
     availableAmount: number;
-    ownerAddress: string;
+    carId: number;
 }
 
 export default function WithdrawOwnerModal({
     closeModal,
     onWithdraw,
     availableAmount,
-    ownerAddress,
+    carId,
 }: WithdrawOwnerModalProps) {
     const [amountInXlm, setAmountInXlm] = useState<string>("");
     const [isSubmitting, setIsSubmitting] = useState(false);
@@ -49,7 +49,7 @@ export default function WithdrawOwnerModal({
         setIsSubmitting(true);
 
         try {
-            await onWithdraw(ownerAddress, amountInStroops);
+            await onWithdraw(carId, amountInStroops);
             // El toast de éxito se maneja en CarList
             closeModal();
         } catch (error) {
@@ -2,4 +2,5 @@
     AVAILABLE = "Available",
     RENTED = "Rented",
     MAINTENANCE = "Maintenance",
+    RETIRED = "Retired",
 }
\ No newline at end of file
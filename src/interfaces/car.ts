@@ -1,6 +1,7 @@
 ﻿import { CarStatus } from "./car-status";
 
 export interface ICar {
+    carId: number;
     brand: string;
     model: string;
     color: string;
@@ -1,6 +1,27 @@
 ﻿import type { ClientOptions } from "@stellar/stellar-sdk/contract";
 import { CarStatus } from "./car-status";
 
+export interface ICarDetails {
+    brand: string;
+    model: string;
+    color: string;
+    passengers: number;
+    ac: boolean;
+}
+
+export interface IRentalQuote {
+    base_price: bigint;
+    admin_commission: bigint;
+    owner_amount: bigint;
+    deposit: bigint;
+    total: bigint;
+}
+
+export interface IOwnerBalance {
+    pending: bigint;
+    settled: bigint;
+}
+
 export interface IBaseContractClient {
     readonly options: ClientOptions;
     toXDR(): string;
@@ -18,32 +39,46 @@ export interface IRentACarContract extends IBaseContractClient {
     add_car: ({
                   owner,
                   price_per_day,
+                  details,
               }: {
         owner: string;
         price_per_day: number;
-    }) => Promise<this>;
+        details: ICarDetails;
+    }) => Promise<this & { result: number }>;
 
-    get_car_status: ({ owner }: { owner: string }) => Promise<CarStatus>;
+    get_car_status: ({ car_id }: { car_id: number }) => Promise<CarStatus>;
+
+    get_quote: ({
+                    car_id,
+                    total_days_to_rent,
+                }: {
+        car_id: number;
+        total_days_to_rent: number;
+    }) => Promise<{ result: IRentalQuote }>;
 
     rental: ({
                  renter,
-                 owner,
+                 car_id,
                  total_days_to_rent,
                  amount,
+                 token,
              }: {
         renter: string;
-        owner: string;
+        car_id: number;
         total_days_to_rent: number;
         amount: number;
+        token: string;
     }) => Promise<this>;
 
-    remove_car: ({ owner }: { owner: string }) => Promise<this>;
+    remove_car: ({ car_id }: { car_id: number }) => Promise<this>;
 
     payout_owner: ({
-                       owner,
+                       car_id,
+                       token,
                        amount,
                    }: {
-        owner: string;
+        car_id: number;
+        token: string;
         amount: number;
     }) => Promise<this>;
 
@@ -54,24 +89,28 @@ export interface IRentACarContract extends IBaseContractClient {
     }) => Promise<this>;
 
     withdraw_admin_commission: ({
+        token,
         amount,
     }: {
+        token: string;
         amount: number;
     }) => Promise<this>;
 
-    get_owner_available_to_withdraw: ({
-        owner,
+    get_car_balance: ({
+        car_id,
+        token,
     }: {
-        owner: string;
+        car_id: number;
+        token: string;
     }) => {
-        simulate: () => Promise<{ result: number }>;
+        simulate: () => Promise<{ result: IOwnerBalance }>;
     };
 
     return_car: ({
         renter,
-        owner,
+        car_id,
     }: {
         renter: string;
-        owner: string;
+        car_id: number;
     }) => Promise<this>;
 }
\ No newline at end of file
@@ -1,3 +1,3 @@
 ﻿import { ICar } from "./car";
 
-export type CreateCar = Omit<ICar, "status">;
\ No newline at end of file
+export type CreateCar = Omit<ICar, "carId" | "status">;
\ No newline at end of file
@@ -13,6 +13,7 @@ import { UserRole } from "../interfaces/user-role";
 import { stellarService } from "../services/stellar.service";
 import { walletService } from "../services/wallet.service";
 import { ONE_XLM_IN_STROOPS } from "../utils/xlm-in-stroops";
+import { TOKEN_ADDRESS } from "../utils/contants";
 import {useStellarAccounts} from "../providers/StellarAccountProvider.tsx";
 import {useEffect, useRef, useState} from "react";
 
@@ -71,6 +72,7 @@ export default function Dashboard() {
             const addCarResult = await contractClient.add_car({
                 owner: ownerAddress,
                 price_per_day: pricePerDay * ONE_XLM_IN_STROOPS,
+                details: { brand, model, color, passengers, ac },
             });
             const xdr = addCarResult.toXDR();
 
@@ -83,6 +85,7 @@ export default function Dashboard() {
             }
 
             const newCar: ICar = {
+                carId: Number(addCarResult.result),
                 brand,
                 model,
                 color,
@@ -154,6 +157,7 @@ export default function Dashboard() {
                 await stellarService.buildClient<IRentACarContract>(walletAddress);
 
             const result = await contractClient.withdraw_admin_commission({
+                token: TOKEN_ADDRESS,
                 amount: amountInStroops,
             });
             const xdr = result.toXDR();
@@ -1,4 +1,5 @@
 ﻿import {
+    Address,
     Asset,
     BASE_FEE,
     Claimant, Contract, contract,
@@ -15,6 +16,7 @@ import {
     HORIZON_URL, SOROBAN_RPC_URL,
     STELLAR_FRIENDBOT_URL,
     STELLAR_NETWORK,
+    TOKEN_ADDRESS,
 } from "../utils/contants.ts";
 import {IKeypair} from "../interfaces/keypair.ts";
 import {AccountBalance} from "../interfaces/account.ts";
@@ -69,7 +71,7 @@ export class StellarService {
             fee: "1000",
             networkPassphrase: this.networkPassphrase,
         })
-            .addOperation(contract.call("get_admin_available_to_withdraw"))
+            .addOperation(contract.call("get_admin_available_to_withdraw", new Address(TOKEN_ADDRESS).toScVal()))
             .setTimeout(30)
             .build();
 
@@ -86,7 +88,7 @@ export class StellarService {
         return Number(value);
     }
 
-    async getOwnerAvailableToWithdraw(ownerAddress: string): Promise<number> {
+    async getCarAvailableToWithdraw(carId: number): Promise<number> {
         // Use contract.Client which handles type conversions automatically
         const server = new rpc.Server(this.rpcUrl, { allowHttp: true });
         
@@ -102,8 +104,10 @@ export class StellarService {
         });
 
         // Call the function using the client which converts types automatically
-        const result = await (contractClient as any).get_owner_available_to_withdraw({
-            owner: ownerAddress,
+        // Only the settled earnings of the car can be paid out
+        const result = await (contractClient as any).get_car_balance({
+            car_id: carId,
+            token: TOKEN_ADDRESS,
         });
 
         // The result already has simulation property
@@ -116,7 +120,7 @@ export class StellarService {
 
         const value = scValToNative(simulationResult.result.retval);
         // Handle BigInt conversion
-        const numericValue = typeof value === 'bigint' ? Number(value) : Number(value);
+        const numericValue = Number(value.settled);
         return numericValue;
     }
 
@@ -5,6 +5,7 @@ const {
   PUBLIC_STELLAR_RPC_URL: SOROBAN_RPC_URL,
   PUBLIC_STELLAR_FRIENDBOT_URL: STELLAR_FRIENDBOT_URL,
   PUBLIC_CONTRACT_ADDRESS: CONTRACT_ADDRESS,
+  PUBLIC_TOKEN_ADDRESS: TOKEN_ADDRESS,
 } = import.meta.env;
 
 export {
@@ -14,4 +15,5 @@ export {
   SOROBAN_RPC_URL,
   STELLAR_FRIENDBOT_URL,
   CONTRACT_ADDRESS,
+  TOKEN_ADDRESS,
 };
\ No newline at end of file